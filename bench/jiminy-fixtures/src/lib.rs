@@ -0,0 +1,26 @@
+#![cfg_attr(target_os = "solana", no_std)]
+//! Jiminy test fixtures - one instruction per helper under test.
+//!
+//! Driven by the Mollusk tests in bench/runner/tests. Kept apart from
+//! bench-jiminy-vault so the CU benchmark program only carries the code
+//! it measures.
+
+pub mod processor;
+
+#[cfg(feature = "bpf-entrypoint")]
+mod entrypoint {
+    use jiminy::prelude::{hopper_entrypoint, no_allocator, nostd_panic_handler, AccountView, Address, ProgramResult};
+
+    hopper_entrypoint!(process_instruction);
+    no_allocator!();
+    nostd_panic_handler!();
+
+    #[allow(dead_code)]
+    pub fn process_instruction(
+        program_id: &Address,
+        accounts: &[AccountView],
+        instruction_data: &[u8],
+    ) -> ProgramResult {
+        crate::processor::process(program_id, accounts, instruction_data)
+    }
+}
@@ -0,0 +1,41 @@
+use jiminy::prelude::*;
+
+extern "C" {
+    fn sol_set_return_data(data: *const u8, length: u64);
+}
+
+pub fn process(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    instruction_dispatch! {
+        _program_id, accounts, instruction_data; ix;
+        0 => check_one_writable(accounts),
+        1 => token_context_transfer(accounts, &ix),
+    }
+}
+
+/// `check_exactly_one_writable` over the first three accounts.
+///
+/// The returned index is set as return data, one byte.
+fn check_one_writable(accounts: &[AccountView]) -> ProgramResult {
+    let [a, b, c, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let index = check_exactly_one_writable(&[a, b, c])?;
+    let data = [index as u8];
+    unsafe { sol_set_return_data(data.as_ptr(), data.len() as u64) };
+    Ok(())
+}
+
+/// `TokenContext::transfer` of a `u64` amount through whichever token
+/// program is passed first.
+fn token_context_transfer(accounts: &[AccountView], ix: &SliceCursor) -> ProgramResult {
+    let [token_program, from, mint, to, authority, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let mut args = SliceCursor::new(ix.data_from_position());
+    let amount = args.read_u64()?;
+    TokenContext::new(token_program)?.transfer(from, mint, to, authority, amount)
+}
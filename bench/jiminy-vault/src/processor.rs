@@ -9,17 +9,20 @@ pub fn process(
     accounts: &[AccountView],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    let mut ix = SliceCursor::new(instruction_data);
-    let tag = ix.read_u8()?;
-
-    match tag {
+    instruction_dispatch! {
+        program_id, accounts, instruction_data; ix;
         0 => process_init_vault(program_id, accounts, &ix),
         1 => process_deposit(program_id, accounts, &ix),
         2 => process_withdraw(program_id, accounts, &ix),
         3 => process_close_vault(program_id, accounts),
         4 => process_guarded_withdraw(program_id, accounts, &ix),
         5 => process_vuln_withdraw(program_id, accounts, &ix),
-        _ => Err(ProgramError::InvalidInstructionData),
+        6 => bench_check_account_fast(program_id, accounts),
+        7 => bench_check_account(program_id, accounts),
+        8 => bench_check_each_signer(accounts),
+        9 => bench_check_signer_repeated(accounts),
+        10 => bench_token_account_view(accounts),
+        11 => bench_token_account_readers(accounts),
     }
 }
 
@@ -256,3 +259,60 @@ fn process_vuln_withdraw(
 
     Ok(())
 }
+
+// ── Check micro-benchmarks ───────────────────────────────────────────────────
+//
+// Each handler runs one check and nothing else, so a pair of them differs
+// only in the check under test. Driven by the "Check micro-benchmarks"
+// section of bench/runner.
+
+/// `check_account_fast`: size from the account metadata, one short borrow.
+fn bench_check_account_fast(program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
+    let vault = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    check_account_fast(vault, program_id, VAULT_DISC, VAULT_LEN)
+}
+
+/// Baseline for [`bench_check_account_fast`]: `check_account`, which
+/// borrows first and checks the size on the borrowed slice.
+fn bench_check_account(program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
+    let vault = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    check_account(vault, program_id, VAULT_DISC, VAULT_LEN)
+}
+
+/// `check_each_signer` over the first three accounts.
+fn bench_check_each_signer(accounts: &[AccountView]) -> ProgramResult {
+    let [a, b, c, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_each_signer(&[a, b, c])
+}
+
+/// Baseline for [`bench_check_each_signer`]: one `check_signer` per account.
+fn bench_check_signer_repeated(accounts: &[AccountView]) -> ProgramResult {
+    let [a, b, c, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_signer(a)?;
+    check_signer(b)?;
+    check_signer(c)
+}
+
+/// `TokenAccount` view: one borrow, then mint, owner, and amount.
+fn bench_token_account_view(accounts: &[AccountView]) -> ProgramResult {
+    let account = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let view = TokenAccount::load(account)?;
+    require_keys_neq!(view.mint(), view.owner(), ProgramError::InvalidAccountData);
+    require!(view.amount() > 0, ProgramError::InsufficientFunds);
+    Ok(())
+}
+
+/// Baseline for [`bench_token_account_view`]: the per-field readers, one
+/// borrow each.
+fn bench_token_account_readers(accounts: &[AccountView]) -> ProgramResult {
+    let account = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let mint = token_account_mint(account)?;
+    let owner = token_account_owner(account)?;
+    require_keys_neq!(mint, owner, ProgramError::InvalidAccountData);
+    require!(token_account_amount(account)? > 0, ProgramError::InsufficientFunds);
+    Ok(())
+}
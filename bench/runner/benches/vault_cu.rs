@@ -35,6 +35,26 @@ fn next_pubkey() -> Pubkey {
     Pubkey::from(bytes)
 }
 
+/// Run one instruction and return `(compute units, succeeded)`.
+fn run(
+    mollusk: &Mollusk,
+    program_id: &Pubkey,
+    data: &[u8],
+    accounts: Vec<AccountMeta>,
+    state: &[(Pubkey, Account)],
+) -> (u64, bool) {
+    let ix = Instruction { program_id: *program_id, accounts, data: data.to_vec() };
+    let result = mollusk.process_instruction(&ix, state);
+    (result.compute_units_consumed, result.program_result.is_ok())
+}
+
+/// Print one micro-benchmark row: the Jiminy form, the baseline, and the delta.
+fn print_pair(label: &str, ((cu, ok), (base_cu, base_ok)): ((u64, bool), (u64, bool))) {
+    let status = if ok && base_ok { "" } else { "  FAIL" };
+    let delta = cu as i64 - base_cu as i64;
+    println!("  {:<36} │ {:>6} CU │ {:>6} CU │ {:>+6}{}", label, cu, base_cu, delta, status);
+}
+
 fn main() {
     let pinocchio_id = next_pubkey();
     let jiminy_id = next_pubkey();
@@ -244,6 +264,73 @@ fn main() {
     }
     println!("└────────────┴──────────────┴──────────────┴────────┘");
 
+    // ── Check micro-benchmarks (Jiminy only) ─────────────────────────────────
+    //
+    // Each row runs two single-check instructions against the same accounts.
+    // Entrypoint and dispatch cost is identical, so the delta is the cost
+    // difference between the two ways of doing the check.
+    println!();
+    println!("Check micro-benchmarks (Jiminy):");
+    println!("  {:<36} │ {:>9} │ {:>9} │ {:>6}", "Check", "Jiminy", "Baseline", "Delta");
+
+    let vault_account = Account {
+        lamports: 2_000_000_000,
+        data: vault_data_41.clone(),
+        owner: jiminy_id,
+        executable: false,
+        rent_epoch: 0,
+    };
+    let check_account_pair = (
+        run(
+            &jiminy_mollusk,
+            &jiminy_id,
+            &[6],
+            vec![AccountMeta::new_readonly(vault_key, false)],
+            &[(vault_key, vault_account.clone())],
+        ),
+        run(
+            &jiminy_mollusk,
+            &jiminy_id,
+            &[7],
+            vec![AccountMeta::new_readonly(vault_key, false)],
+            &[(vault_key, vault_account.clone())],
+        ),
+    );
+    print_pair("check_account_fast vs check_account", check_account_pair);
+
+    let signers = [next_pubkey(), next_pubkey(), next_pubkey()];
+    let signer_metas = || -> Vec<AccountMeta> {
+        signers.iter().map(|k| AccountMeta::new_readonly(*k, true)).collect()
+    };
+    let signer_state: Vec<_> =
+        signers.iter().map(|k| (*k, authority_account_system.clone())).collect();
+    let each_signer_pair = (
+        run(&jiminy_mollusk, &jiminy_id, &[8], signer_metas(), &signer_state),
+        run(&jiminy_mollusk, &jiminy_id, &[9], signer_metas(), &signer_state),
+    );
+    print_pair("check_each_signer vs 3x signer", each_signer_pair);
+
+    // 165-byte SPL Token account image: mint, owner, amount = 500.
+    let mut token_data = vec![0u8; 165];
+    token_data[0..32].fill(1);
+    token_data[32..64].fill(2);
+    token_data[64..72].copy_from_slice(&500u64.to_le_bytes());
+    token_data[108] = 1; // initialized
+    let token_key = next_pubkey();
+    let token_state = [(token_key, Account {
+        lamports: 2_039_280,
+        data: token_data,
+        owner: jiminy_id,
+        executable: false,
+        rent_epoch: 0,
+    })];
+    let token_meta = || vec![AccountMeta::new_readonly(token_key, false)];
+    let token_view_pair = (
+        run(&jiminy_mollusk, &jiminy_id, &[10], token_meta(), &token_state),
+        run(&jiminy_mollusk, &jiminy_id, &[11], token_meta(), &token_state),
+    );
+    print_pair("TokenAccount vs field readers", token_view_pair);
+
     // ══════════════════════════════════════════════════════════════════════════
     // ── SECURITY FLAW DEMO ───────────────────────────────────────────────────
     // ══════════════════════════════════════════════════════════════════════════
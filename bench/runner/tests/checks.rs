@@ -0,0 +1,66 @@
+//! Mollusk tests for the check helpers, run against bench-jiminy-fixtures.
+//!
+//! Requires `target/deploy/bench_jiminy_fixtures.so`:
+//!
+//! ```sh
+//! rustup run solana -- cargo build --release --target sbf-solana-solana -p bench-jiminy-fixtures
+//! cp target/sbf-solana-solana/release/bench_jiminy_fixtures.so target/deploy/
+//! ```
+//!
+//! Then from bench/runner/:
+//!
+//! ```sh
+//! cargo test
+//! ```
+
+use mollusk_svm::result::Check;
+use mollusk_svm::Mollusk;
+use solana_account::Account;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+
+const PROGRAM_ID: Pubkey = Pubkey::new_from_array([7u8; 32]);
+
+fn mollusk() -> Mollusk {
+    Mollusk::new(&PROGRAM_ID, "../../target/deploy/bench_jiminy_fixtures")
+}
+
+/// Run tag 0 (`check_exactly_one_writable`) with the given writable flags.
+fn run_one_writable(writable: [bool; 3], checks: &[Check]) {
+    let keys = [1u8, 2, 3].map(|b| Pubkey::new_from_array([b; 32]));
+    let accounts = keys
+        .iter()
+        .zip(writable)
+        .map(|(key, w)| {
+            if w {
+                AccountMeta::new(*key, false)
+            } else {
+                AccountMeta::new_readonly(*key, false)
+            }
+        })
+        .collect();
+    let state: Vec<_> = keys
+        .iter()
+        .map(|key| (*key, Account::new(1_000_000, 0, &PROGRAM_ID)))
+        .collect();
+    let ix = Instruction { program_id: PROGRAM_ID, accounts, data: vec![0] };
+    mollusk().process_and_validate_instruction(&ix, &state, checks);
+}
+
+#[test]
+fn exactly_one_writable_rejects_none() {
+    run_one_writable([false, false, false], &[Check::err(ProgramError::InvalidArgument)]);
+}
+
+#[test]
+fn exactly_one_writable_returns_the_index() {
+    run_one_writable([true, false, false], &[Check::success(), Check::return_data(&[0])]);
+    run_one_writable([false, true, false], &[Check::success(), Check::return_data(&[1])]);
+    run_one_writable([false, false, true], &[Check::success(), Check::return_data(&[2])]);
+}
+
+#[test]
+fn exactly_one_writable_rejects_two() {
+    run_one_writable([true, false, true], &[Check::err(ProgramError::InvalidArgument)]);
+}
@@ -0,0 +1,120 @@
+//! Mollusk tests for the jiminy-escrow cancel path.
+//!
+//! Requires `target/deploy/jiminy_escrow.so`:
+//!
+//! ```sh
+//! rustup run solana -- cargo build --release --target sbf-solana-solana -p jiminy-escrow
+//! cp target/sbf-solana-solana/release/jiminy_escrow.so target/deploy/
+//! ```
+
+use mollusk_svm::program::keyed_account_for_system_program;
+use mollusk_svm::result::{InstructionResult, ProgramResult};
+use mollusk_svm::Mollusk;
+use solana_account::Account;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+
+const PROGRAM_ID: Pubkey = Pubkey::new_from_array([8u8; 32]);
+const CREATOR: Pubkey = Pubkey::new_from_array([1u8; 32]);
+const ESCROW: Pubkey = Pubkey::new_from_array([2u8; 32]);
+const RECIPIENT: Pubkey = Pubkey::new_from_array([3u8; 32]);
+const LINKED: Pubkey = Pubkey::new_from_array([4u8; 32]);
+const STRANGER: Pubkey = Pubkey::new_from_array([5u8; 32]);
+
+const NOW: i64 = 1_000;
+const TIMEOUT: i64 = 2_000;
+
+fn mollusk() -> Mollusk {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, "../../target/deploy/jiminy_escrow");
+    mollusk.sysvars.clock.unix_timestamp = NOW;
+    mollusk
+}
+
+/// Create an escrow of 1 SOL with `TIMEOUT` and the given linked account,
+/// returning the account state after the create.
+fn create(mollusk: &Mollusk, linked: Pubkey) -> Vec<(Pubkey, Account)> {
+    let mut data = vec![0];
+    data.extend_from_slice(&1_000_000_000u64.to_le_bytes());
+    data.extend_from_slice(RECIPIENT.as_ref());
+    data.extend_from_slice(&TIMEOUT.to_le_bytes());
+    data.extend_from_slice(linked.as_ref());
+    let (system_id, system_account) = keyed_account_for_system_program();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(CREATOR, true),
+            AccountMeta::new(ESCROW, true),
+            AccountMeta::new_readonly(system_id, false),
+        ],
+        data,
+    };
+    let state = [
+        (CREATOR, Account::new(10_000_000_000, 0, &system_id)),
+        (ESCROW, Account::default()),
+        (system_id, system_account),
+    ];
+    let result = mollusk.process_instruction(&ix, &state);
+    assert!(result.program_result.is_ok(), "{:?}", result.program_result);
+    result.resulting_accounts
+}
+
+/// Cancel the escrow, optionally passing `extra` as the 4th account.
+fn cancel(
+    mollusk: &Mollusk,
+    mut state: Vec<(Pubkey, Account)>,
+    extra: Option<Pubkey>,
+) -> InstructionResult {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(CREATOR, true),
+        AccountMeta::new(ESCROW, false),
+        AccountMeta::new(CREATOR, false),
+    ];
+    if let Some(extra) = extra {
+        accounts.push(AccountMeta::new_readonly(extra, false));
+        state.push((extra, Account::default()));
+    }
+    let ix = Instruction { program_id: PROGRAM_ID, accounts, data: vec![2] };
+    mollusk.process_instruction(&ix, &state)
+}
+
+#[test]
+fn cancel_before_timeout_fails() {
+    let mollusk = mollusk();
+    let state = create(&mollusk, Pubkey::default());
+    let result = cancel(&mollusk, state, None);
+    assert_eq!(result.program_result, ProgramResult::Failure(ProgramError::InvalidArgument));
+}
+
+#[test]
+fn cancel_before_timeout_fails_with_extra_account() {
+    let mollusk = mollusk();
+    let state = create(&mollusk, Pubkey::default());
+    let result = cancel(&mollusk, state, Some(STRANGER));
+    assert_eq!(result.program_result, ProgramResult::Failure(ProgramError::InvalidArgument));
+}
+
+#[test]
+fn cancel_before_timeout_rejects_wrong_linked_account() {
+    let mollusk = mollusk();
+    let state = create(&mollusk, LINKED);
+    let result = cancel(&mollusk, state, Some(STRANGER));
+    assert_eq!(result.program_result, ProgramResult::Failure(ProgramError::InvalidArgument));
+}
+
+#[test]
+fn cancel_with_closed_linked_account_succeeds() {
+    let mollusk = mollusk();
+    let state = create(&mollusk, LINKED);
+    let result = cancel(&mollusk, state, Some(LINKED));
+    assert!(result.program_result.is_ok(), "{:?}", result.program_result);
+}
+
+#[test]
+fn cancel_after_timeout_succeeds() {
+    let mut mollusk = mollusk();
+    let state = create(&mollusk, Pubkey::default());
+    mollusk.sysvars.clock.unix_timestamp = TIMEOUT + 1;
+    let result = cancel(&mollusk, state, Some(STRANGER));
+    assert!(result.program_result.is_ok(), "{:?}", result.program_result);
+}
@@ -0,0 +1,102 @@
+//! Mollusk tests for `TokenContext` transfers against SPL Token and
+//! Token-2022, run through bench-jiminy-fixtures.
+//!
+//! Requires `target/deploy/bench_jiminy_fixtures.so`; see `checks.rs`.
+
+use mollusk_svm::result::{InstructionResult, ProgramResult};
+use mollusk_svm::Mollusk;
+use mollusk_svm_programs_token::{token, token2022};
+use solana_account::Account;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+
+const PROGRAM_ID: Pubkey = Pubkey::new_from_array([7u8; 32]);
+const MINT: Pubkey = Pubkey::new_from_array([1u8; 32]);
+const FROM: Pubkey = Pubkey::new_from_array([2u8; 32]);
+const TO: Pubkey = Pubkey::new_from_array([3u8; 32]);
+const AUTHORITY: Pubkey = Pubkey::new_from_array([4u8; 32]);
+
+/// 82-byte mint: no mint authority, supply 1_000, 6 decimals, initialized.
+fn mint_account(token_program: &Pubkey) -> Account {
+    let mut data = vec![0u8; 82];
+    data[36..44].copy_from_slice(&1_000u64.to_le_bytes());
+    data[44] = 6;
+    data[45] = 1;
+    Account { lamports: 1_461_600, data, owner: *token_program, executable: false, rent_epoch: 0 }
+}
+
+/// 165-byte initialized token account for `MINT`, owned by `AUTHORITY`.
+fn token_account(token_program: &Pubkey, amount: u64) -> Account {
+    let mut data = vec![0u8; 165];
+    data[0..32].copy_from_slice(MINT.as_ref());
+    data[32..64].copy_from_slice(AUTHORITY.as_ref());
+    data[64..72].copy_from_slice(&amount.to_le_bytes());
+    data[108] = 1;
+    Account { lamports: 2_039_280, data, owner: *token_program, executable: false, rent_epoch: 0 }
+}
+
+fn amount_of(account: &Account) -> u64 {
+    u64::from_le_bytes(account.data[64..72].try_into().unwrap())
+}
+
+/// Run tag 1 (`TokenContext::transfer`) moving `amount` from `FROM` to `TO`.
+fn transfer(
+    mollusk: &Mollusk,
+    program: (Pubkey, Account),
+    amount: u64,
+) -> InstructionResult {
+    let token_program = program.0;
+    let mut data = vec![1];
+    data.extend_from_slice(&amount.to_le_bytes());
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new(FROM, false),
+            AccountMeta::new_readonly(MINT, false),
+            AccountMeta::new(TO, false),
+            AccountMeta::new_readonly(AUTHORITY, true),
+        ],
+        data,
+    };
+    let state = [
+        program,
+        (FROM, token_account(&token_program, 500)),
+        (MINT, mint_account(&token_program)),
+        (TO, token_account(&token_program, 0)),
+        (AUTHORITY, Account::new(1_000_000, 0, &Pubkey::default())),
+    ];
+    mollusk.process_instruction(&ix, &state)
+}
+
+fn mollusk() -> Mollusk {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, "../../target/deploy/bench_jiminy_fixtures");
+    token::add_program(&mut mollusk);
+    token2022::add_program(&mut mollusk);
+    mollusk
+}
+
+#[test]
+fn transfers_through_spl_token() {
+    let result = transfer(&mollusk(), token::keyed_account(), 200);
+    assert!(result.program_result.is_ok(), "{:?}", result.program_result);
+    assert_eq!(amount_of(result.get_account(&FROM).unwrap()), 300);
+    assert_eq!(amount_of(result.get_account(&TO).unwrap()), 200);
+}
+
+#[test]
+fn transfers_through_token_2022() {
+    let result = transfer(&mollusk(), token2022::keyed_account(), 200);
+    assert!(result.program_result.is_ok(), "{:?}", result.program_result);
+    assert_eq!(amount_of(result.get_account(&FROM).unwrap()), 300);
+    assert_eq!(amount_of(result.get_account(&TO).unwrap()), 200);
+}
+
+#[test]
+fn rejects_non_token_program() {
+    let mollusk = mollusk();
+    let fake = Pubkey::new_from_array([9u8; 32]);
+    let result = transfer(&mollusk, (fake, Account::new(1_000_000, 0, &Pubkey::default())), 200);
+    assert_eq!(result.program_result, ProgramResult::Failure(ProgramError::IncorrectProgramId));
+}
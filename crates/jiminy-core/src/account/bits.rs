@@ -57,3 +57,189 @@ pub fn write_flags_at(data: &mut [u8], offset: usize, value: u8) -> Result<(), P
     *byte = value;
     Ok(())
 }
+
+/// Set bit `n` of the byte at `offset`, in place.
+///
+/// Returns `AccountDataTooSmall` if `offset` is out of bounds and
+/// `InvalidArgument` if `n >= 8`.
+///
+/// ```rust,ignore
+/// set_bit_at(&mut raw, 2, FLAG_ACCEPTED)?;
+/// ```
+#[inline(always)]
+pub fn set_bit_at(data: &mut [u8], offset: usize, n: u8) -> Result<(), ProgramError> {
+    let byte = byte_at_mut(data, offset, n)?;
+    *byte = set_bit(*byte, n);
+    Ok(())
+}
+
+/// Clear bit `n` of the byte at `offset`, in place.
+#[inline(always)]
+pub fn clear_bit_at(data: &mut [u8], offset: usize, n: u8) -> Result<(), ProgramError> {
+    let byte = byte_at_mut(data, offset, n)?;
+    *byte = clear_bit(*byte, n);
+    Ok(())
+}
+
+/// Toggle bit `n` of the byte at `offset`, in place.
+#[inline(always)]
+pub fn toggle_bit_at(data: &mut [u8], offset: usize, n: u8) -> Result<(), ProgramError> {
+    let byte = byte_at_mut(data, offset, n)?;
+    *byte = toggle_bit(*byte, n);
+    Ok(())
+}
+
+/// Bounds-check `offset` and the bit index for the `*_bit_at` helpers.
+#[inline(always)]
+fn byte_at_mut(data: &mut [u8], offset: usize, n: u8) -> Result<&mut u8, ProgramError> {
+    if n >= 8 {
+        return Err(ProgramError::InvalidArgument);
+    }
+    data.get_mut(offset).ok_or(ProgramError::AccountDataTooSmall)
+}
+
+// ── Multi-bit fields ─────────────────────────────────────────────────────────
+
+/// Read the `width`-bit field starting at bit `shift` of a byte.
+///
+/// For small enums packed into a status byte. `InvalidArgument` if the
+/// field runs past bit 7.
+///
+/// ```rust,ignore
+/// // bits 2..5 hold the order side
+/// let side = read_bit_field(status, 2, 3)?;
+/// ```
+#[inline(always)]
+pub fn read_bit_field(byte: u8, shift: u8, width: u8) -> Result<u8, ProgramError> {
+    let mask = field_mask(shift, width)?;
+    Ok((byte >> shift) & mask)
+}
+
+/// Replace the `width`-bit field starting at bit `shift` with `value`,
+/// returning the modified byte. Other bits are left alone.
+///
+/// `InvalidArgument` if the field runs past bit 7 or `value` does not fit
+/// in `width` bits.
+#[inline(always)]
+pub fn write_bit_field(byte: u8, shift: u8, width: u8, value: u8) -> Result<u8, ProgramError> {
+    let mask = field_mask(shift, width)?;
+    if value & !mask != 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+    Ok((byte & !(mask << shift)) | (value << shift))
+}
+
+/// Unshifted mask for a `width`-bit field, checking it fits at `shift`.
+#[inline(always)]
+fn field_mask(shift: u8, width: u8) -> Result<u8, ProgramError> {
+    if width == 0 || shift as u16 + width as u16 > 8 {
+        return Err(ProgramError::InvalidArgument);
+    }
+    Ok((0xFFu16 >> (8 - width)) as u8)
+}
+
+// ── Wide bitfields ───────────────────────────────────────────────────────────
+
+/// Generate read/set/clear/toggle bit helpers and flag-mask checks for a wide
+/// integer. Bit indexes at or above the width return `InvalidArgument`.
+macro_rules! impl_wide_bits {
+    (
+        $( $ty:ty, $bits:literal =>
+            $read:ident, $set:ident, $clear:ident, $toggle:ident, $all:ident, $any:ident; )*
+    ) => {
+        $(
+            #[doc = concat!("Read bit `n` from a `", stringify!($ty), "`. `InvalidArgument` if `n >= ", stringify!($bits), "`.")]
+            #[inline(always)]
+            pub fn $read(value: $ty, n: u8) -> Result<bool, ProgramError> {
+                if n >= $bits {
+                    return Err(ProgramError::InvalidArgument);
+                }
+                Ok((value >> n) & 1 == 1)
+            }
+
+            #[doc = concat!("Set bit `n` in a `", stringify!($ty), "`. `InvalidArgument` if `n >= ", stringify!($bits), "`.")]
+            #[inline(always)]
+            pub fn $set(value: $ty, n: u8) -> Result<$ty, ProgramError> {
+                if n >= $bits {
+                    return Err(ProgramError::InvalidArgument);
+                }
+                Ok(value | (1 << n))
+            }
+
+            #[doc = concat!("Clear bit `n` in a `", stringify!($ty), "`. `InvalidArgument` if `n >= ", stringify!($bits), "`.")]
+            #[inline(always)]
+            pub fn $clear(value: $ty, n: u8) -> Result<$ty, ProgramError> {
+                if n >= $bits {
+                    return Err(ProgramError::InvalidArgument);
+                }
+                Ok(value & !(1 << n))
+            }
+
+            #[doc = concat!("Toggle bit `n` in a `", stringify!($ty), "`. `InvalidArgument` if `n >= ", stringify!($bits), "`.")]
+            #[inline(always)]
+            pub fn $toggle(value: $ty, n: u8) -> Result<$ty, ProgramError> {
+                if n >= $bits {
+                    return Err(ProgramError::InvalidArgument);
+                }
+                Ok(value ^ (1 << n))
+            }
+
+            #[doc = concat!("Return `true` if ALL bits in `mask` are set in a `", stringify!($ty), "`.")]
+            #[inline(always)]
+            pub fn $all(value: $ty, mask: $ty) -> bool {
+                value & mask == mask
+            }
+
+            #[doc = concat!("Return `true` if ANY bit in `mask` is set in a `", stringify!($ty), "`.")]
+            #[inline(always)]
+            pub fn $any(value: $ty, mask: $ty) -> bool {
+                value & mask != 0
+            }
+        )*
+    };
+}
+
+impl_wide_bits! {
+    u16, 16 =>
+        read_bit16, set_bit16, clear_bit16, toggle_bit16, check_flags_u16, check_any_flag_u16;
+    u32, 32 =>
+        read_bit32, set_bit32, clear_bit32, toggle_bit32, check_flags_u32, check_any_flag_u32;
+    u64, 64 =>
+        read_bit64, set_bit64, clear_bit64, toggle_bit64, check_flags_u64, check_any_flag_u64;
+}
+
+// ── Bitmap scanning ──────────────────────────────────────────────────────────
+
+/// Generate set-bit counting and first-free-slot lookup for an integer
+/// bitmap. Both are `const fn` so known inputs fold at compile time.
+macro_rules! impl_bit_scan {
+    ($( $ty:ty => $count:ident, $first_clear:ident; )*) => {
+        $(
+            #[doc = concat!("Number of set bits in a `", stringify!($ty), "`.")]
+            #[inline(always)]
+            pub const fn $count(value: $ty) -> u32 {
+                value.count_ones()
+            }
+
+            #[doc = concat!("Index of the lowest unset bit in a `", stringify!($ty), "`, or `None` if every bit is set.")]
+            ///
+            /// Pairs with the set helpers for slot allocation in an
+            /// occupancy bitmap: find the first free slot, then mark it.
+            #[inline(always)]
+            pub const fn $first_clear(value: $ty) -> Option<u8> {
+                if value == <$ty>::MAX {
+                    None
+                } else {
+                    Some(value.trailing_ones() as u8)
+                }
+            }
+        )*
+    };
+}
+
+impl_bit_scan! {
+    u8 => count_set_bits, first_clear_bit;
+    u16 => count_set_bits16, first_clear_bit16;
+    u32 => count_set_bits32, first_clear_bit32;
+    u64 => count_set_bits64, first_clear_bit64;
+}
@@ -7,7 +7,9 @@
 //! Typed methods are generated by internal `impl_cursor_read!` /
 //! `impl_cursor_write!` macros. Same API, way less copy-paste.
 
-use hopper_runtime::{ProgramError, Address};
+use hopper_runtime::{ProgramError, AccountView, Address, Ref};
+
+use super::{pod_from_bytes, pod_read, FixedLayout, Pod};
 
 // ── Internal code-gen macros ─────────────────────────────────────────────────
 
@@ -106,12 +108,45 @@ impl<'a> SliceCursor<'a> {
         read_i128 -> i128, 16;
     }
 
+    /// Read a one-byte enum tag, rejecting values above `max`.
+    ///
+    /// Returns `InvalidInstructionData` for an unknown variant, so the
+    /// caller's `match` only has to cover `0..=max`.
+    ///
+    /// ```rust,ignore
+    /// let side = match ix.read_tag(1)? {
+    ///     0 => Side::Bid,
+    ///     _ => Side::Ask,
+    /// };
+    /// ```
+    #[inline(always)]
+    pub fn read_tag(&mut self, max: u8) -> Result<u8, ProgramError> {
+        let tag = self.read_u8()?;
+        if tag > max {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        Ok(tag)
+    }
+
     /// `0` → `false`, anything else → `true`.
     #[inline(always)]
     pub fn read_bool(&mut self) -> Result<bool, ProgramError> {
         Ok(self.read_u8()? != 0)
     }
 
+    /// `0` → `false`, `1` → `true`, anything else → `InvalidAccountData`.
+    ///
+    /// Use for security-relevant flags, where a corrupt or crafted byte
+    /// should be rejected rather than read as `true`.
+    #[inline(always)]
+    pub fn read_bool_strict(&mut self) -> Result<bool, ProgramError> {
+        match self.read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+
     #[inline(always)]
     pub fn read_i8(&mut self) -> Result<i8, ProgramError> {
         Ok(self.read_u8()? as i8)
@@ -128,6 +163,122 @@ impl<'a> SliceCursor<'a> {
         Ok(arr.into())
     }
 
+    /// Read `n` raw bytes as a subslice of the input.
+    #[inline(always)]
+    fn read_slice(&mut self, n: usize) -> Result<&'a [u8], ProgramError> {
+        let end = self.pos.checked_add(n).ok_or(ProgramError::AccountDataTooSmall)?;
+        if end > self.data.len() {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+        let out = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(out)
+    }
+
+    /// Read a `u32` LE length followed by that many bytes (borsh `Vec<u8>`).
+    ///
+    /// Zero-copy: the returned slice borrows the cursor's input.
+    ///
+    /// ```rust,ignore
+    /// let memo = ix.read_len_prefixed_bytes()?;
+    /// require!(memo.len() <= MAX_MEMO, MyError::MemoTooLong);
+    /// ```
+    #[inline(always)]
+    pub fn read_len_prefixed_bytes(&mut self) -> Result<&'a [u8], ProgramError> {
+        let len = self.read_u32()? as usize;
+        self.read_slice(len)
+    }
+
+    /// Read a `u32` LE count followed by that many 32-byte addresses
+    /// (borsh `Vec<Pubkey>`).
+    ///
+    /// The whole list is bounds-checked up front; the iterator then yields
+    /// each address without further checks.
+    ///
+    /// ```rust,ignore
+    /// for recipient in ix.read_len_prefixed_addresses()? {
+    ///     // ...
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn read_len_prefixed_addresses(
+        &mut self,
+    ) -> Result<impl Iterator<Item = Address> + 'a, ProgramError> {
+        let count = self.read_u32()? as usize;
+        let len = count.checked_mul(32).ok_or(ProgramError::AccountDataTooSmall)?;
+        let bytes = self.read_slice(len)?;
+        Ok(bytes.chunks_exact(32).map(|c| {
+            let arr: [u8; 32] = c.try_into().unwrap();
+            Address::from(arr)
+        }))
+    }
+
+    /// Read a 32-byte address as a reference into the input, without
+    /// copying it.
+    ///
+    /// For keys that are only compared and dropped; use
+    /// [`read_address`](Self::read_address) when the key needs to be stored.
+    ///
+    /// ```rust,ignore
+    /// let expected = ix.read_address_ref()?;
+    /// require_keys_eq!(expected, authority.address(), MyError::WrongAuthority);
+    /// ```
+    #[inline(always)]
+    pub fn read_address_ref(&mut self) -> Result<&'a Address, ProgramError> {
+        let bytes = self.read_slice(32)?;
+        // SAFETY: Address is #[repr(transparent)] over [u8; 32] (align 1,
+        // every bit pattern valid), the same assumption zero_copy_layout!
+        // overlays rely on. The slice is exactly 32 bytes and borrows the
+        // cursor's input for 'a.
+        Ok(unsafe { &*(bytes.as_ptr() as *const Address) })
+    }
+
+    /// Borrow the next `T::SIZE` bytes as a `&'a T`.
+    ///
+    /// For naturally aligned layouts read at an aligned position. The
+    /// pointer is checked against `align_of::<T>()` on every target, so a
+    /// host test over an unaligned buffer fails the same way on-chain
+    /// would instead of hitting UB. Returns `InvalidAccountData` when
+    /// misaligned and `AccountDataTooSmall` when short; the position only
+    /// moves on success. For packed structs or unknown alignment use
+    /// [`read_pod_unaligned`](Self::read_pod_unaligned).
+    #[inline(always)]
+    pub fn read_pod<T: Pod + FixedLayout>(&mut self) -> Result<&'a T, ProgramError> {
+        let value = pod_from_bytes::<T>(self.data_from_position())?;
+        self.pos += T::SIZE;
+        Ok(value)
+    }
+
+    /// Copy the next `T::SIZE` bytes out as a `T`, at any alignment.
+    ///
+    /// Uses `read_unaligned`, so it is correct for `#[repr(C, packed)]`
+    /// structs and odd offsets alike, at the cost of a copy.
+    #[inline(always)]
+    pub fn read_pod_unaligned<T: Pod + FixedLayout>(&mut self) -> Result<T, ProgramError> {
+        pod_read::<T>(self.read_slice(T::SIZE)?)
+    }
+
+    /// Read a `u8` bump and verify `account` is the PDA for `seeds` + bump.
+    ///
+    /// Fuses bump parsing with [`assert_pda_with_bump`](crate::check::assert_pda_with_bump)
+    /// so a client-supplied bump is never used unverified. Returns the bump
+    /// for later signing.
+    ///
+    /// ```rust,ignore
+    /// let bump = ix.read_bump_verify(vault, &[b"vault", authority.as_ref()], program_id)?;
+    /// ```
+    #[inline(always)]
+    pub fn read_bump_verify(
+        &mut self,
+        account: &AccountView,
+        seeds: &[&[u8]],
+        program_id: &Address,
+    ) -> Result<u8, ProgramError> {
+        let bump = self.read_u8()?;
+        crate::check::assert_pda_with_bump(account, seeds, bump, program_id)?;
+        Ok(bump)
+    }
+
     /// Skip `n` bytes without reading them.
     #[inline(always)]
     pub fn skip(&mut self, n: usize) -> Result<(), ProgramError> {
@@ -149,6 +300,69 @@ impl<'a> SliceCursor<'a> {
         }
     }
 
+    /// Split off the next `len` bytes as their own cursor and advance
+    /// past them.
+    ///
+    /// The sub-cursor cannot read beyond the region, so a nested record
+    /// parser can't overrun into the following field. Returns
+    /// `AccountDataTooSmall` (without advancing) if fewer than `len` bytes
+    /// remain.
+    ///
+    /// ```rust,ignore
+    /// let mut order = cur.sub(ORDER_LEN)?;
+    /// let price = order.read_u64()?;
+    /// let qty = order.read_u64()?;
+    /// order.finish()?;
+    /// let next_field = cur.read_u8()?; // parent resumes after the order
+    /// ```
+    #[inline(always)]
+    pub fn sub(&mut self, len: usize) -> Result<SliceCursor<'a>, ProgramError> {
+        Ok(SliceCursor::new(self.read_slice(len)?))
+    }
+
+    /// Iterate successive `N`-byte records from the current position.
+    ///
+    /// Each record advances the cursor; iteration stops when fewer than
+    /// `N` bytes remain, leaving any trailing partial record unread (check
+    /// with [`finish`](Self::finish) if the payload must divide evenly).
+    /// Yields nothing for `N == 0`.
+    ///
+    /// ```rust,ignore
+    /// let mut cur = SliceCursor::new(header_payload(&data)?);
+    /// let best_bid = cur.read_u64()?;
+    /// for level in cur.iter_records::<LEVEL_LEN>() {
+    ///     let price = u64::from_le_bytes(level[0..8].try_into().unwrap());
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn iter_records<const N: usize>(&mut self) -> impl Iterator<Item = &'a [u8; N]> + '_ {
+        core::iter::from_fn(move || {
+            if N == 0 || self.remaining() < N {
+                return None;
+            }
+            <&[u8; N]>::try_from(self.read_slice(N).ok()?).ok()
+        })
+    }
+
+    /// Consume the cursor, requiring every byte to have been read.
+    ///
+    /// Catches layout drift such as a forgotten field. Returns
+    /// `InvalidAccountData` if anything is left unread.
+    ///
+    /// ```rust,ignore
+    /// let mut cur = SliceCursor::new(header_payload(&data)?);
+    /// let balance = cur.read_u64()?;
+    /// let authority = cur.read_address()?;
+    /// cur.finish()?;
+    /// ```
+    #[inline(always)]
+    pub fn finish(self) -> Result<(), ProgramError> {
+        if self.remaining() != 0 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+
     /// Create a cursor for instruction data with minimum length validation.
     #[inline(always)]
     pub fn from_instruction(data: &'a [u8], min_len: usize) -> Result<Self, ProgramError> {
@@ -159,6 +373,50 @@ impl<'a> SliceCursor<'a> {
     }
 }
 
+// ── PayloadRef ───────────────────────────────────────────────────────────────
+
+/// An account data borrow that starts reading at the payload.
+///
+/// Holds the borrow guard, so cursors made from it cannot outlive the
+/// borrow. Returned by
+/// [`AccountList::next_account_payload`](crate::account::AccountList::next_account_payload).
+///
+/// ```rust,ignore
+/// let (vault, payload) =
+///     accs.next_account_payload(program_id, VAULT_DISC, VAULT_LEN, HEADER_LEN)?;
+/// let mut cur = payload.cursor();
+/// let balance = cur.read_u64()?;
+/// ```
+pub struct PayloadRef<'a> {
+    data: Ref<'a, [u8]>,
+    offset: usize,
+}
+
+impl<'a> PayloadRef<'a> {
+    /// Wrap `data`, with the payload starting at `offset`.
+    ///
+    /// Returns `AccountDataTooSmall` if `offset` is past the end.
+    #[inline(always)]
+    pub fn new(data: Ref<'a, [u8]>, offset: usize) -> Result<Self, ProgramError> {
+        if offset > data.len() {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+        Ok(Self { data, offset })
+    }
+
+    /// The payload bytes.
+    #[inline(always)]
+    pub fn bytes(&self) -> &[u8] {
+        &self.data[self.offset..]
+    }
+
+    /// A fresh cursor at the start of the payload.
+    #[inline(always)]
+    pub fn cursor(&self) -> SliceCursor<'_> {
+        SliceCursor::new(self.bytes())
+    }
+}
+
 // ── DataWriter ───────────────────────────────────────────────────────────────
 
 /// Zero-copy write cursor over a mutable byte slice.
@@ -184,12 +442,49 @@ impl<'a> DataWriter<'a> {
         Self { data, pos: 0 }
     }
 
+    /// Writer limited to the first `capacity` bytes of `data`.
+    ///
+    /// Separates the logical record from the physical allocation: a write
+    /// past `capacity` fails with `AccountDataTooSmall` even if the account
+    /// has room. Fails with `AccountDataTooSmall` if `capacity > data.len()`.
+    ///
+    /// ```rust,ignore
+    /// let mut w = DataWriter::with_capacity(&mut raw[HEADER_LEN..], VAULT_BODY_LEN)?;
+    /// w.write_u64(0)?;
+    /// w.write_address(&authority)?;
+    /// w.finish()?; // layout bug if the body wasn't written exactly
+    /// ```
+    #[inline(always)]
+    pub fn with_capacity(data: &'a mut [u8], capacity: usize) -> Result<Self, ProgramError> {
+        if capacity > data.len() {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+        Ok(Self { data: &mut data[..capacity], pos: 0 })
+    }
+
     /// Number of bytes written so far.
     #[inline(always)]
     pub fn written(&self) -> usize {
         self.pos
     }
 
+    /// Logical length this writer may fill.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Require the writer to have filled its whole capacity.
+    ///
+    /// Returns `InvalidAccountData` if `written() != capacity()`.
+    #[inline(always)]
+    pub fn finish(self) -> Result<(), ProgramError> {
+        if self.pos != self.data.len() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+
     #[inline(always)]
     pub fn write_u8(&mut self, val: u8) -> Result<(), ProgramError> {
         if self.pos >= self.data.len() {
@@ -233,6 +528,28 @@ impl<'a> DataWriter<'a> {
         self.pos = end;
         Ok(())
     }
+
+    /// Write a `u32` LE length followed by `src`, the inverse of
+    /// [`SliceCursor::read_len_prefixed_bytes`].
+    ///
+    /// Nothing is written unless the prefix and bytes both fit.
+    ///
+    /// ```rust,ignore
+    /// w.write_len_prefixed_bytes(b"vault #1")?;
+    /// ```
+    #[inline(always)]
+    pub fn write_len_prefixed_bytes(&mut self, src: &[u8]) -> Result<(), ProgramError> {
+        let len = u32::try_from(src.len()).map_err(|_| ProgramError::InvalidArgument)?;
+        let start = self.pos + 4;
+        let end = start.checked_add(src.len()).ok_or(ProgramError::AccountDataTooSmall)?;
+        if end > self.data.len() {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+        self.data[self.pos..start].copy_from_slice(&len.to_le_bytes());
+        self.data[start..end].copy_from_slice(src);
+        self.pos = end;
+        Ok(())
+    }
 }
 
 // ── Init helpers ─────────────────────────────────────────────────────────────
@@ -255,3 +572,20 @@ pub fn write_discriminator(data: &mut [u8], discriminator: u8) -> Result<(), Pro
     data[0] = discriminator;
     Ok(())
 }
+
+/// Read a table of `N` bump seeds stored contiguously at `offset`.
+///
+/// For config accounts that hold the bumps of several related PDAs.
+/// Pair with [`verify_pdas_with_bumps`](crate::check::verify_pdas_with_bumps).
+///
+/// ```rust,ignore
+/// let bumps: [u8; 3] = read_bumps(&data, BUMPS_OFFSET)?;
+/// ```
+#[inline(always)]
+pub fn read_bumps<const N: usize>(data: &[u8], offset: usize) -> Result<[u8; N], ProgramError> {
+    let end = offset.checked_add(N).ok_or(ProgramError::AccountDataTooSmall)?;
+    if end > data.len() {
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+    Ok(data[offset..end].try_into().unwrap())
+}
@@ -3,15 +3,23 @@
 //! Defines the canonical 16-byte header for Jiminy account layouts:
 //!
 //! ```text
-//! ┌────────────┬─────────┬───────┬────────────────────┬──────────────┐
-//! │ disc (1B)  │ ver (1B)│ flags │  layout_id         │  reserved    │
-//! │   u8       │   u8    │ u16   │  [u8; 8]           │  [u8; 4]     │
-//! └────────────┴─────────┴───────┴────────────────────┴──────────────┘
+//! ┌────────────┬─────────┬───────┬────────────────────┬──────┬──────────┐
+//! │ disc (1B)  │ ver (1B)│ flags │  layout_id         │ crc8 │ reserved │
+//! │   u8       │   u8    │ u16   │  [u8; 8]           │  u8  │ [u8; 3]  │
+//! └────────────┴─────────┴───────┴────────────────────┴──────┴──────────┘
 //! ```
 //!
+//! Byte 12 holds the optional payload CRC-8 (see [`write_header_checksummed`]).
+//! It is the first reserved byte rather than byte 3, because byte 3 is the
+//! high byte of the u16 `flags` field.
+//!
 //! Programs that adopt this header can use a single [`check_header`] call
 //! to validate discriminator + version + layout_id in one shot, and
 //! [`header_payload`] to get the body slice after the header.
+//!
+//! The header does not store the data length. The runtime's
+//! `AccountView::data_len()` is authoritative, so there is nothing to
+//! update after a realloc and nothing that can drift from the real size.
 
 use hopper_runtime::ProgramError;
 
@@ -95,6 +103,119 @@ pub fn write_header(
     Ok(())
 }
 
+// ── Payload checksum ─────────────────────────────────────────────────────────
+//
+// Opt-in CRC-8 of the payload, stored in the first reserved byte (12).
+// It catches accidental corruption, such as a migration or realloc that
+// shifted fields, not malicious edits: anyone who can write the payload
+// can recompute the checksum.
+
+/// Header byte holding the payload checksum.
+pub const HEADER_CHECKSUM_OFFSET: usize = 12;
+
+/// [`write_header`], then store the CRC-8 of the payload in byte 12.
+///
+/// Call it after the payload is written, and re-stamp with
+/// [`update_header_checksum`] after every later payload write.
+///
+/// ```rust,ignore
+/// write_header_checksummed(&mut data, VAULT_DISC, 1, &VAULT_LAYOUT_ID)?;
+/// verify_header_checksum(&data)?;
+/// ```
+#[inline(always)]
+pub fn write_header_checksummed(
+    data: &mut [u8],
+    discriminator: u8,
+    version: u8,
+    layout_id: &[u8; 8],
+) -> Result<(), ProgramError> {
+    write_header(data, discriminator, version, layout_id)?;
+    update_header_checksum(data)
+}
+
+/// Recompute the payload checksum and store it in byte 12. Leaves the
+/// rest of the header alone.
+#[inline(always)]
+pub fn update_header_checksum(data: &mut [u8]) -> Result<(), ProgramError> {
+    if data.len() < HEADER_LEN {
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+    data[HEADER_CHECKSUM_OFFSET] = crc8(&data[HEADER_LEN..]);
+    Ok(())
+}
+
+/// Verify byte 12 matches the CRC-8 of the payload.
+///
+/// Returns `InvalidAccountData` on a mismatch.
+#[inline(always)]
+pub fn verify_header_checksum(data: &[u8]) -> Result<(), ProgramError> {
+    if data.len() < HEADER_LEN {
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+    if data[HEADER_CHECKSUM_OFFSET] != crc8(&data[HEADER_LEN..]) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(())
+}
+
+/// CRC-8 (polynomial `0x07`, init 0, no reflection), bitwise.
+#[inline(always)]
+fn crc8(bytes: &[u8]) -> u8 {
+    let mut crc = 0u8;
+    let mut i = 0;
+    while i < bytes.len() {
+        crc ^= bytes[i];
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 0x80 != 0 { (crc << 1) ^ 0x07 } else { crc << 1 };
+            bit += 1;
+        }
+        i += 1;
+    }
+    crc
+}
+
+/// Move a header from `from_version` to `to_version` in place.
+///
+/// Verifies the discriminator and that the stored version is exactly
+/// `from_version`, then writes `to_version` and the new layout's
+/// `layout_id` (the id hashes the version, so it changes too). Flags
+/// and reserved bytes are preserved, unlike [`write_header`].
+///
+/// Returns `InvalidAccountData` on a disc or version mismatch and
+/// `InvalidArgument` unless `to_version > from_version`.
+///
+/// Realloc first if the new layout is larger, then migrate the header,
+/// then fill in the appended fields:
+///
+/// ```rust,ignore
+/// safe_realloc(vault, VaultV2::LEN, payer, system_program)?;
+/// let mut data = vault.try_borrow_mut()?;
+/// migrate_header(&mut data, Vault::DISC, Vault::VERSION, VaultV2::VERSION, &VaultV2::LAYOUT_ID)?;
+/// VaultV2::overlay_mut(&mut data)?.fee_bps = 30;
+/// ```
+#[inline(always)]
+pub fn migrate_header(
+    data: &mut [u8],
+    expected_disc: u8,
+    from_version: u8,
+    to_version: u8,
+    to_layout_id: &[u8; 8],
+) -> Result<(), ProgramError> {
+    if to_version <= from_version {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if data.len() < HEADER_LEN {
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+    if data[0] != expected_disc || data[1] != from_version {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    data[1] = to_version;
+    data[4..12].copy_from_slice(to_layout_id);
+    Ok(())
+}
+
 /// Validate discriminator, minimum version, and layout_id in one call.
 #[inline(always)]
 pub fn check_header(
@@ -118,6 +239,46 @@ pub fn check_header(
     Ok(())
 }
 
+/// Compile-time identity of a Jiminy account type.
+///
+/// Gives each layout one place to declare its discriminator, version,
+/// and layout_id, so the header helpers can be generic over the type.
+/// `zero_copy_layout!` implements this automatically. Guard against two
+/// types sharing a tag with `unique_discriminators!(A::DISC, B::DISC)`.
+#[doc(alias = "Discriminator")]
+pub trait AccountType {
+    /// Account discriminator byte.
+    const DISC: u8;
+    /// Account schema version.
+    const VERSION: u8;
+    /// Deterministic ABI fingerprint.
+    const LAYOUT_ID: [u8; 8];
+}
+
+/// Write the header for account type `T`.
+///
+/// ```rust,ignore
+/// write_header_for::<Vault>(&mut data)?;
+/// ```
+#[inline(always)]
+pub fn write_header_for<T: AccountType>(data: &mut [u8]) -> Result<(), ProgramError> {
+    write_header(data, T::DISC, T::VERSION, &T::LAYOUT_ID)
+}
+
+/// Validate the header against account type `T`.
+///
+/// Same checks as [`check_header`] with `T::VERSION` as the minimum version.
+#[inline(always)]
+pub fn check_header_for<T: AccountType>(data: &[u8]) -> Result<(), ProgramError> {
+    check_header(data, T::DISC, T::VERSION, &T::LAYOUT_ID)
+}
+
+/// Read the discriminator byte (byte 0) from account data.
+#[inline(always)]
+pub fn read_discriminator(data: &[u8]) -> Result<u8, ProgramError> {
+    data.first().copied().ok_or(ProgramError::AccountDataTooSmall)
+}
+
 /// Read the version byte from account data.
 #[inline(always)]
 pub fn read_version(data: &[u8]) -> Result<u8, ProgramError> {
@@ -136,6 +297,66 @@ pub fn read_header_flags(data: &[u8]) -> Result<u16, ProgramError> {
     Ok(u16::from_le_bytes([data[2], data[3]]))
 }
 
+/// Set bit `n` of the header flags field (bytes 2..4), in place.
+///
+/// Returns `AccountDataTooSmall` if `data` is shorter than the flags
+/// field and `InvalidArgument` if `n >= 16`.
+///
+/// ```rust,ignore
+/// set_header_flag(&mut raw, FLAG_ACCEPTED)?;
+/// ```
+#[inline(always)]
+pub fn set_header_flag(data: &mut [u8], n: u8) -> Result<(), ProgramError> {
+    let flags = read_header_flags(data)?;
+    write_flags(data, super::bits::set_bit16(flags, n)?);
+    Ok(())
+}
+
+/// Clear bit `n` of the header flags field (bytes 2..4), in place.
+#[inline(always)]
+pub fn clear_header_flag(data: &mut [u8], n: u8) -> Result<(), ProgramError> {
+    let flags = read_header_flags(data)?;
+    write_flags(data, super::bits::clear_bit16(flags, n)?);
+    Ok(())
+}
+
+/// Toggle bit `n` of the header flags field (bytes 2..4), in place.
+#[inline(always)]
+pub fn toggle_header_flag(data: &mut [u8], n: u8) -> Result<(), ProgramError> {
+    let flags = read_header_flags(data)?;
+    write_flags(data, super::bits::toggle_bit16(flags, n)?);
+    Ok(())
+}
+
+/// Clear `clear_mask` then set `set_mask` in the header flags field, in
+/// one read and one write.
+///
+/// Stores `(flags & !clear_mask) | set_mask`, so a bit in both masks ends
+/// up set. Returns `AccountDataTooSmall` if `data` is shorter than the
+/// flags field.
+///
+/// ```rust,ignore
+/// update_header_flags(&mut raw, FLAG_PENDING, FLAG_ACTIVE)?; // PENDING -> ACTIVE
+/// ```
+#[inline(always)]
+pub fn update_header_flags(
+    data: &mut [u8],
+    clear_mask: u16,
+    set_mask: u16,
+) -> Result<(), ProgramError> {
+    let flags = read_header_flags(data)?;
+    write_flags(data, (flags & !clear_mask) | set_mask);
+    Ok(())
+}
+
+/// Store `flags` at bytes 2..4. Caller has already bounds-checked.
+#[inline(always)]
+fn write_flags(data: &mut [u8], flags: u16) {
+    let bytes = flags.to_le_bytes();
+    data[2] = bytes[0];
+    data[3] = bytes[1];
+}
+
 /// Read the layout_id field (bytes 4..12).
 #[inline(always)]
 pub fn read_layout_id(data: &[u8]) -> Result<[u8; 8], ProgramError> {
@@ -189,6 +410,225 @@ pub fn body_mut(data: &mut [u8]) -> Result<&mut [u8], ProgramError> {
     header_payload_mut(data)
 }
 
+// ── Typed header views ───────────────────────────────────────────────────────
+
+/// Field getters shared by [`HeaderRef`] and [`HeaderMut`]. `self.bytes`
+/// is always exactly `HEADER_LEN` long, so no getter re-checks bounds.
+macro_rules! impl_header_getters {
+    ($ty:ident) => {
+        impl $ty<'_> {
+            /// Discriminator (byte 0).
+            #[inline(always)]
+            pub fn discriminator(&self) -> u8 {
+                self.bytes[0]
+            }
+
+            /// Version (byte 1).
+            #[inline(always)]
+            pub fn version(&self) -> u8 {
+                self.bytes[1]
+            }
+
+            /// Flags (bytes 2..4, u16 LE).
+            #[inline(always)]
+            pub fn flags(&self) -> u16 {
+                u16::from_le_bytes([self.bytes[2], self.bytes[3]])
+            }
+
+            /// Whether bit `n` of the flags is set. `false` for `n >= 16`.
+            #[inline(always)]
+            pub fn flag(&self, n: u8) -> bool {
+                n < 16 && (self.flags() >> n) & 1 == 1
+            }
+
+            /// Layout id (bytes 4..12).
+            #[inline(always)]
+            pub fn layout_id(&self) -> [u8; 8] {
+                let mut id = [0u8; 8];
+                id.copy_from_slice(&self.bytes[4..12]);
+                id
+            }
+        }
+    };
+}
+
+/// Read-only view of the 16-byte header, bounds-checked once on load.
+///
+/// ```rust,ignore
+/// let data = account.try_borrow()?;
+/// let header = HeaderRef::load(&data)?;
+/// require!(header.version() >= 2, ProgramError::InvalidAccountData);
+/// let frozen = header.flag(FLAG_FROZEN);
+/// ```
+#[derive(Clone, Copy)]
+pub struct HeaderRef<'a> {
+    bytes: &'a [u8; HEADER_LEN],
+}
+
+impl<'a> HeaderRef<'a> {
+    /// View the header at the start of `data`.
+    #[inline(always)]
+    pub fn load(data: &'a [u8]) -> Result<Self, ProgramError> {
+        if data.len() < HEADER_LEN {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+        Ok(Self { bytes: data[..HEADER_LEN].try_into().unwrap() })
+    }
+}
+
+impl_header_getters!(HeaderRef);
+
+/// Mutable view of the 16-byte header, bounds-checked once on load.
+///
+/// ```rust,ignore
+/// let mut data = account.try_borrow_mut()?;
+/// let mut header = HeaderMut::load(&mut data)?;
+/// header.set_flag(FLAG_ACCEPTED)?;
+/// ```
+pub struct HeaderMut<'a> {
+    bytes: &'a mut [u8; HEADER_LEN],
+}
+
+impl<'a> HeaderMut<'a> {
+    /// View the header at the start of `data`.
+    #[inline(always)]
+    pub fn load(data: &'a mut [u8]) -> Result<Self, ProgramError> {
+        if data.len() < HEADER_LEN {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+        Ok(Self { bytes: (&mut data[..HEADER_LEN]).try_into().unwrap() })
+    }
+
+    /// Write the discriminator (byte 0).
+    #[inline(always)]
+    pub fn set_discriminator(&mut self, disc: u8) {
+        self.bytes[0] = disc;
+    }
+
+    /// Write the version (byte 1).
+    #[inline(always)]
+    pub fn set_version(&mut self, version: u8) {
+        self.bytes[1] = version;
+    }
+
+    /// Write the whole flags field (bytes 2..4).
+    #[inline(always)]
+    pub fn set_flags(&mut self, flags: u16) {
+        self.bytes[2..4].copy_from_slice(&flags.to_le_bytes());
+    }
+
+    /// Set bit `n` of the flags. `InvalidArgument` if `n >= 16`.
+    #[inline(always)]
+    pub fn set_flag(&mut self, n: u8) -> Result<(), ProgramError> {
+        self.set_flags(super::bits::set_bit16(self.flags(), n)?);
+        Ok(())
+    }
+
+    /// Clear bit `n` of the flags. `InvalidArgument` if `n >= 16`.
+    #[inline(always)]
+    pub fn clear_flag(&mut self, n: u8) -> Result<(), ProgramError> {
+        self.set_flags(super::bits::clear_bit16(self.flags(), n)?);
+        Ok(())
+    }
+
+    /// Write the layout id (bytes 4..12).
+    #[inline(always)]
+    pub fn set_layout_id(&mut self, layout_id: &[u8; 8]) {
+        self.bytes[4..12].copy_from_slice(layout_id);
+    }
+}
+
+impl_header_getters!(HeaderMut);
+
+// ── 8-byte discriminator header ──────────────────────────────────────────────
+//
+// Alternate convention for programs that want Anchor-strength type tags:
+//
+//   0..8    disc      [u8; 8]
+//   8       version   u8
+//   9       reserved  u8
+//  10..12   flags     u16 LE
+//  12..20   layout_id [u8; 8]
+//  20..24   reserved  [u8; 4]
+//
+// The payload starts at byte 24, so it stays 8-byte aligned.
+
+/// Size of the 8-byte-discriminator header.
+pub const HEADER8_LEN: usize = 24;
+
+/// First 8 bytes of `sha256(name)`, for use as an 8-byte discriminator.
+///
+/// Pass `"account:<Name>"` to match Anchor's account discriminators.
+///
+/// ```rust,ignore
+/// const VAULT_DISC8: [u8; 8] = disc8_of("account:Vault");
+/// ```
+#[inline(always)]
+pub const fn disc8_of(name: &str) -> [u8; 8] {
+    let hash = crate::__sha256_const(name.as_bytes());
+    [hash[0], hash[1], hash[2], hash[3], hash[4], hash[5], hash[6], hash[7]]
+}
+
+/// Write an 8-byte-discriminator header (flags = 0, reserved = 0).
+#[inline(always)]
+pub fn write_header8(
+    data: &mut [u8],
+    discriminator: &[u8; 8],
+    version: u8,
+    layout_id: &[u8; 8],
+) -> Result<(), ProgramError> {
+    if data.len() < HEADER8_LEN {
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+    data[0..8].copy_from_slice(discriminator);
+    data[8] = version;
+    data[9..12].fill(0);
+    data[12..20].copy_from_slice(layout_id);
+    data[20..24].fill(0);
+    Ok(())
+}
+
+/// Validate an 8-byte discriminator, minimum version, and layout_id.
+#[inline(always)]
+pub fn check_header8(
+    data: &[u8],
+    expected_discriminator: &[u8; 8],
+    min_version: u8,
+    layout_id: &[u8; 8],
+) -> Result<(), ProgramError> {
+    if data.len() < HEADER8_LEN {
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+    if data[0..8] != *expected_discriminator {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if data[8] < min_version {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if data[12..20] != *layout_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(())
+}
+
+/// Return the body slice after the 24-byte header.
+#[inline(always)]
+pub fn header8_payload(data: &[u8]) -> Result<&[u8], ProgramError> {
+    if data.len() < HEADER8_LEN {
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+    Ok(&data[HEADER8_LEN..])
+}
+
+/// Return the mutable body slice after the 24-byte header.
+#[inline(always)]
+pub fn header8_payload_mut(data: &mut [u8]) -> Result<&mut [u8], ProgramError> {
+    if data.len() < HEADER8_LEN {
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+    Ok(&mut data[HEADER8_LEN..])
+}
+
 // ── Pod / FixedLayout ────────────────────────────────────────────────────────
 
 // SAFETY: AccountHeader is #[repr(C)], Copy, 16 bytes, and all bit patterns
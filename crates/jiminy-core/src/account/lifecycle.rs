@@ -7,7 +7,7 @@
 use hopper_runtime::{ProgramError, AccountView, ProgramResult};
 
 use crate::math::{checked_add, checked_sub};
-use crate::check::rent_exempt_min;
+use crate::check::{rent_exempt_min, SYSTEM_PROGRAM_ID};
 
 // ── Close ────────────────────────────────────────────────────────────────────
 
@@ -59,6 +59,38 @@ pub fn safe_close_with_sentinel(
     Ok(())
 }
 
+/// Close `account` and explicitly reassign it to the system program.
+///
+/// [`safe_close`] relies on `close_unchecked`, which clears the owner as
+/// a side effect of the runtime's close path. This variant spells every
+/// step out instead: drain lamports into `destination`, zero the data,
+/// shrink it to 0 bytes, then assign the system program as owner. Use it
+/// when the address is expected to be `create_account`-ed again in a
+/// later transaction (PDA recycling), so the account is system-owned and
+/// empty whatever runtime processes the close.
+///
+/// Both accounts **must be writable**, and `account` must be owned by the
+/// calling program.
+///
+/// # Safety
+/// Caller must guarantee no active borrows exist on `account` at call time.
+#[inline(always)]
+pub fn safe_close_reassign(account: &AccountView, destination: &AccountView) -> ProgramResult {
+    let lamports = account.lamports();
+    let new_dest = checked_add(destination.lamports(), lamports)?;
+    {
+        let mut data = account.try_borrow_mut()?;
+        zero_init(&mut data);
+    }
+    account.resize(0)?;
+    destination.set_lamports(new_dest);
+    account.set_lamports(0);
+    // SAFETY: The try_borrow_mut() scope above has ended (data dropped).
+    // No active borrows on account remain.
+    unsafe { account.assign(&SYSTEM_PROGRAM_ID) };
+    Ok(())
+}
+
 /// Check that an account has not been revived after closure.
 ///
 /// Returns `InvalidAccountData` if the first 8 bytes match the dead sentinel.
@@ -86,6 +118,72 @@ pub fn check_alive(account: &AccountView, discriminator: u8) -> ProgramResult {
 
 // ── Realloc ──────────────────────────────────────────────────────────────────
 
+/// Maximum number of bytes an account may grow by within one instruction.
+pub const MAX_PERMITTED_DATA_INCREASE: usize = 10_240;
+
+/// Maximum account data length (10 MiB).
+pub const MAX_PERMITTED_DATA_LENGTH: usize = 10 * 1024 * 1024;
+
+/// Pre-flight for growing `account` to `new_len` bytes.
+///
+/// Fails with `InvalidRealloc` if the growth exceeds
+/// [`MAX_PERMITTED_DATA_INCREASE`] or the new length exceeds
+/// [`MAX_PERMITTED_DATA_LENGTH`]. Otherwise returns the lamports that
+/// must be added to keep the account rent-exempt at the new size
+/// (0 if its current balance already covers it).
+///
+/// ```rust,ignore
+/// let top_up = check_realloc(vault, new_len)?;
+/// if top_up > 0 {
+///     safe_transfer_sol(payer, vault, top_up)?;
+/// }
+/// vault.resize(new_len)?;
+/// ```
+#[inline(always)]
+pub fn check_realloc(account: &AccountView, new_len: usize) -> Result<u64, ProgramError> {
+    realloc_top_up(account.data_len(), account.lamports(), new_len)
+}
+
+/// Data-level core of [`check_realloc`].
+///
+/// Takes the current length and lamport balance instead of an account,
+/// so the growth and rent rules can be evaluated without one.
+#[inline(always)]
+pub fn realloc_top_up(current_len: usize, lamports: u64, new_len: usize) -> Result<u64, ProgramError> {
+    if new_len > MAX_PERMITTED_DATA_LENGTH
+        || new_len.saturating_sub(current_len) > MAX_PERMITTED_DATA_INCREASE
+    {
+        return Err(ProgramError::InvalidRealloc);
+    }
+    Ok(rent_exempt_min(new_len).saturating_sub(lamports))
+}
+
+/// Resize `account` to `new_len` bytes, zero-filling any grown region
+/// when `zero_new` is set.
+///
+/// The runtime does not promise the new tail is clean, so without
+/// zeroing a grown account can expose stale bytes to the next reader.
+/// Shrinking ignores `zero_new`. Lamports are not moved; top up first
+/// with [`check_realloc`] or use [`safe_realloc`].
+///
+/// The Jiminy header has no length field, so nothing in it changes.
+///
+/// ```rust,ignore
+/// let top_up = check_realloc(list, new_len)?;
+/// safe_transfer_sol(payer, list, top_up)?;
+/// realloc(list, new_len, true)?;
+/// ```
+#[inline(always)]
+pub fn realloc(account: &AccountView, new_len: usize, zero_new: bool) -> ProgramResult {
+    let old_len = account.data_len();
+    account.resize(new_len)?;
+    if zero_new && new_len > old_len {
+        let mut data = account.try_borrow_mut()?;
+        data[old_len..new_len].fill(0);
+    }
+    Ok(())
+}
+
 /// Resize an account and top up lamports from `payer` to maintain rent exemption.
 ///
 /// Both `account` and `payer` must be writable. `payer` must be a signer.
@@ -6,9 +6,17 @@
 use hopper_runtime::{ProgramError, AccountView, Address};
 
 use crate::check::{
-    check_account, check_executable, check_signer, check_system_program, check_writable,
+    check_account, check_discriminator, check_executable, check_owner, check_signer, check_size,
+    check_system_program, check_uninitialized, check_writable, rent_exempt_min,
 };
 
+use super::{write_header, zero_init, PayloadRef, HEADER_LEN};
+
+/// Base SPL Token account length, checked by
+/// [`AccountList::next_n_token_accounts`].
+#[cfg(feature = "programs")]
+const TOKEN_ACCOUNT_LEN: usize = 165;
+
 /// Iterator-style account accessor with inline constraint checks.
 ///
 /// ```rust,ignore
@@ -35,6 +43,36 @@ impl<'a> AccountList<'a> {
         self.accounts.len().saturating_sub(self.pos)
     }
 
+    /// Index of the next account to be consumed.
+    #[inline(always)]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Rewind to the first account for another validation pass.
+    ///
+    /// ```rust,ignore
+    /// let mut accs = AccountList::new(accounts);
+    /// let _ = accs.next_account(program_id, POOL_DISC, POOL_LEN)?; // structural pass
+    /// accs.reset();
+    /// let pool = accs.next_writable()?;                               // mutation pass
+    /// ```
+    #[inline(always)]
+    pub fn reset(&mut self) {
+        self.pos = 0;
+    }
+
+    /// Move to account index `pos`. `pos == len` is allowed (nothing
+    /// left to consume); anything past that is `NotEnoughAccountKeys`.
+    #[inline(always)]
+    pub fn seek(&mut self, pos: usize) -> Result<(), ProgramError> {
+        if pos > self.accounts.len() {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        self.pos = pos;
+        Ok(())
+    }
+
     /// Consume the next account with no additional checks.
     #[inline(always)]
     #[allow(clippy::should_implement_trait)]
@@ -47,6 +85,27 @@ impl<'a> AccountList<'a> {
         Ok(acc)
     }
 
+    /// Consume the next `n` accounts as one slice, with no additional checks.
+    ///
+    /// For batch instructions taking a run of same-kind accounts. Nothing
+    /// is consumed if fewer than `n` remain.
+    ///
+    /// ```rust,ignore
+    /// let count = ix.read_u8()? as usize;
+    /// for vault in accs.next_n(count)? {
+    ///     check_account(vault, program_id, VAULT_DISC, VAULT_LEN)?;
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn next_n(&mut self, n: usize) -> Result<&'a [AccountView], ProgramError> {
+        if n > self.remaining() {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        let accs = &self.accounts[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(accs)
+    }
+
     /// Consume the next account and verify it signed the transaction.
     #[inline(always)]
     pub fn next_signer(&mut self) -> Result<&'a AccountView, ProgramError> {
@@ -107,6 +166,36 @@ impl<'a> AccountList<'a> {
         Ok(acc)
     }
 
+    /// [`next_account`](Self::next_account) that also returns the data
+    /// borrow, positioned at the payload.
+    ///
+    /// Pass [`HEADER_LEN`] as `payload_offset` for Jiminy-header accounts
+    /// and `1` for a bare discriminator byte. The borrow is held until the
+    /// [`PayloadRef`] drops; drop it before borrowing the account mutably.
+    ///
+    /// ```rust,ignore
+    /// let (vault, payload) =
+    ///     accs.next_account_payload(program_id, VAULT_DISC, VAULT_LEN, HEADER_LEN)?;
+    /// let mut cur = payload.cursor();
+    /// let balance = cur.read_u64()?;
+    /// let authority = cur.read_address()?;
+    /// ```
+    #[inline(always)]
+    pub fn next_account_payload(
+        &mut self,
+        program_id: &Address,
+        discriminator: u8,
+        min_len: usize,
+        payload_offset: usize,
+    ) -> Result<(&'a AccountView, PayloadRef<'a>), ProgramError> {
+        let acc = self.next()?;
+        check_owner(acc, program_id)?;
+        let data = acc.try_borrow()?;
+        check_size(&data, min_len)?;
+        check_discriminator(&data, discriminator)?;
+        Ok((acc, PayloadRef::new(data, payload_offset)?))
+    }
+
     /// Consume the next account as a writable state account.
     #[inline(always)]
     pub fn next_writable_account(
@@ -144,8 +233,86 @@ impl<'a> AccountList<'a> {
         Ok(acc)
     }
 
+    /// Consume the standard init preamble: `(payer, new_account, system_program)`.
+    ///
+    /// - `payer` must be a writable signer
+    /// - `new_account` must be writable and have no data yet
+    /// - `system_program` must be the system program
+    ///
+    /// ```rust,ignore
+    /// let (payer, vault, _system) = accs.next_init_triple()?;
+    /// init_account!(payer, vault, program_id, Vault)?;
+    /// ```
+    #[inline(always)]
+    pub fn next_init_triple(
+        &mut self,
+    ) -> Result<(&'a AccountView, &'a AccountView, &'a AccountView), ProgramError> {
+        let payer = self.next_writable_signer()?;
+        let new_account = self.next_writable()?;
+        check_uninitialized(new_account)?;
+        let system_program = self.next_system_program()?;
+        Ok((payer, new_account, system_program))
+    }
+
+    /// Consume the next account, create it, and write its header.
+    ///
+    /// The slot must be writable with no data yet. Creates it through the
+    /// system program with `space` bytes (header included), funded by
+    /// `payer` to the rent-exempt minimum and owned by `program_id`, then
+    /// zeroes it and writes the 16-byte Jiminy header. The payload starts
+    /// at [`HEADER_LEN`]. The system program still has to be among the
+    /// instruction's accounts for the CPI.
+    ///
+    /// ```rust,ignore
+    /// let payer = accs.next_writable_signer()?;
+    /// let vault = accs.next_init(payer, program_id, VAULT_DISC, 1, &VAULT_LAYOUT_ID, VAULT_LEN)?;
+    /// let _system = accs.next_system_program()?;
+    /// let mut raw = vault.try_borrow_mut()?;
+    /// let v = Vault::overlay_mut(&mut raw)?;
+    /// ```
+    ///
+    /// For a [`zero_copy_layout!`](crate::zero_copy_layout) type,
+    /// [`init_account!`](crate::init_account) fills in the header fields.
+    #[inline(always)]
+    pub fn next_init(
+        &mut self,
+        payer: &AccountView,
+        program_id: &Address,
+        discriminator: u8,
+        version: u8,
+        layout_id: &[u8; 8],
+        space: usize,
+    ) -> Result<&'a AccountView, ProgramError> {
+        if space < HEADER_LEN {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+        let acc = self.next_writable()?;
+        check_uninitialized(acc)?;
+        hopper_runtime::system::instructions::CreateAccount {
+            from: payer,
+            to: acc,
+            lamports: rent_exempt_min(space),
+            space: space as u64,
+            owner: program_id,
+        }
+        .invoke()?;
+
+        let mut data = acc.try_borrow_mut()?;
+        zero_init(&mut data);
+        write_header(&mut data, discriminator, version, layout_id)?;
+        Ok(acc)
+    }
+
     /// Consume the next account and verify it is the Clock sysvar.
+    ///
+    /// Pairs with the [`sysvar`](crate::sysvar) readers:
+    ///
+    /// ```rust,ignore
+    /// let clock = accs.next_clock()?;
+    /// let now = read_clock_timestamp(clock)?;
+    /// ```
     #[cfg(feature = "programs")]
+    #[doc(alias = "next_clock_sysvar")]
     #[inline(always)]
     pub fn next_clock(&mut self) -> Result<&'a AccountView, ProgramError> {
         let acc = self.next()?;
@@ -168,6 +335,7 @@ impl<'a> AccountList<'a> {
 
     /// Consume the next account and verify it is the Rent sysvar.
     #[cfg(feature = "programs")]
+    #[doc(alias = "next_rent_sysvar")]
     #[inline(always)]
     pub fn next_rent(&mut self) -> Result<&'a AccountView, ProgramError> {
         let acc = self.next()?;
@@ -176,4 +344,123 @@ impl<'a> AccountList<'a> {
         }
         Ok(acc)
     }
+
+    // ── Labeled variants ─────────────────────────────────────────────────────
+
+    /// [`next_signer`](Self::next_signer) that logs `"<label>: <index>"`
+    /// on failure when the `log` feature is on.
+    ///
+    /// Without `log` the label is discarded and this is `next_signer`.
+    ///
+    /// ```rust,ignore
+    /// let authority = accs.next_signer_ctx("authority")?;
+    /// // on failure, program log: "authority: 2"
+    /// ```
+    #[inline(always)]
+    pub fn next_signer_ctx(
+        &mut self,
+        label: &'static str,
+    ) -> Result<&'a AccountView, ProgramError> {
+        let index = self.pos;
+        with_ctx(self.next_signer(), label, index)
+    }
+
+    /// [`next_writable`](Self::next_writable) with a failure label.
+    #[inline(always)]
+    pub fn next_writable_ctx(
+        &mut self,
+        label: &'static str,
+    ) -> Result<&'a AccountView, ProgramError> {
+        let index = self.pos;
+        with_ctx(self.next_writable(), label, index)
+    }
+
+    /// [`next_writable_signer`](Self::next_writable_signer) with a failure label.
+    #[inline(always)]
+    pub fn next_writable_signer_ctx(
+        &mut self,
+        label: &'static str,
+    ) -> Result<&'a AccountView, ProgramError> {
+        let index = self.pos;
+        with_ctx(self.next_writable_signer(), label, index)
+    }
+
+    /// [`next_account`](Self::next_account) with a failure label.
+    #[inline(always)]
+    pub fn next_account_ctx(
+        &mut self,
+        label: &'static str,
+        program_id: &Address,
+        discriminator: u8,
+        min_len: usize,
+    ) -> Result<&'a AccountView, ProgramError> {
+        let index = self.pos;
+        with_ctx(self.next_account(program_id, discriminator, min_len), label, index)
+    }
+
+    /// [`next_writable_account`](Self::next_writable_account) with a failure label.
+    #[inline(always)]
+    pub fn next_writable_account_ctx(
+        &mut self,
+        label: &'static str,
+        program_id: &Address,
+        discriminator: u8,
+        min_len: usize,
+    ) -> Result<&'a AccountView, ProgramError> {
+        let index = self.pos;
+        with_ctx(self.next_writable_account(program_id, discriminator, min_len), label, index)
+    }
+
+    /// Consume the next `n` accounts, each owned by SPL Token or Token-2022
+    /// and at least 165 bytes (the base token-account layout).
+    ///
+    /// Fails with `IncorrectProgramId` or `AccountDataTooSmall` on the first
+    /// account that isn't; the position is left unchanged in that case.
+    /// Mint and authority checks are left to the caller (see the
+    /// `jiminy-solana` token readers).
+    ///
+    /// ```rust,ignore
+    /// for src in accs.next_n_token_accounts(count)? {
+    ///     check_token_account_mint(src, &sweep_mint)?;
+    /// }
+    /// ```
+    #[cfg(feature = "programs")]
+    #[inline(always)]
+    pub fn next_n_token_accounts(&mut self, n: usize) -> Result<&'a [AccountView], ProgramError> {
+        let start = self.pos;
+        let accs = self.next_n(n)?;
+        let mut i = 0;
+        while i < accs.len() {
+            let acc = &accs[i];
+            let token_owned = acc.owned_by(&crate::programs::TOKEN)
+                || acc.owned_by(&crate::programs::TOKEN_2022);
+            if !token_owned {
+                self.pos = start;
+                return Err(ProgramError::IncorrectProgramId);
+            }
+            if acc.data_len() < TOKEN_ACCOUNT_LEN {
+                self.pos = start;
+                return Err(ProgramError::AccountDataTooSmall);
+            }
+            i += 1;
+        }
+        Ok(accs)
+    }
+}
+
+/// Log `"<label>: <index>"` if `result` is an error. Compiles to a plain
+/// pass-through without the `log` feature.
+#[inline(always)]
+fn with_ctx<T>(
+    result: Result<T, ProgramError>,
+    label: &'static str,
+    index: usize,
+) -> Result<T, ProgramError> {
+    #[cfg(feature = "log")]
+    if result.is_err() {
+        crate::log::log_val(label, index as u64);
+    }
+    #[cfg(not(feature = "log"))]
+    let _ = (label, index);
+    result
 }
@@ -23,23 +23,39 @@ pub mod writer;
 // ── Re-exports ───────────────────────────────────────────────────────────────
 
 pub use header::{
-    AccountHeader, HEADER_FORMAT, HEADER_LEN, body, body_mut, check_header, check_layout_id,
-    header_payload, header_payload_mut, read_header_flags, read_layout_id, read_version,
-    write_header,
+    AccountHeader, AccountType, HEADER_FORMAT, HEADER_LEN, body, body_mut, check_header,
+    check_header_for, check_layout_id, write_header_for,
+    clear_header_flag, header_payload, header_payload_mut, read_discriminator, read_header_flags,
+    migrate_header, read_layout_id, read_version, set_header_flag, toggle_header_flag,
+    update_header_flags, write_header, update_header_checksum, verify_header_checksum, write_header_checksummed,
+    HEADER_CHECKSUM_OFFSET,
+    check_header8, disc8_of, header8_payload, header8_payload_mut, write_header8, HEADER8_LEN,
+    HeaderMut, HeaderRef,
 };
 pub use reader::AccountReader;
 pub use writer::AccountWriter;
-pub use cursor::{DataWriter, SliceCursor, write_discriminator, zero_init};
+pub use cursor::{
+    DataWriter, PayloadRef, SliceCursor, read_bumps, write_discriminator, zero_init,
+};
 pub use pod::{Pod, FixedLayout, pod_from_bytes, pod_from_bytes_mut, pod_read, pod_write};
 pub use collection::{ZeroCopySlice, ZeroCopySliceMut, ZeroCopyIter};
 pub use lifecycle::{
-    CLOSE_SENTINEL, safe_close, safe_close_with_sentinel, check_not_revived,
-    check_alive, safe_realloc, safe_realloc_shrink,
+    CLOSE_SENTINEL, safe_close, safe_close_reassign, safe_close_with_sentinel,
+    check_not_revived, check_alive, realloc, safe_realloc, safe_realloc_shrink, check_realloc, realloc_top_up,
+    MAX_PERMITTED_DATA_INCREASE, MAX_PERMITTED_DATA_LENGTH,
 };
 pub use list::AccountList;
 pub use bits::{
     check_any_flag, check_flags, clear_bit, read_bit, read_flags_at, set_bit,
     toggle_bit, write_flags_at,
+    clear_bit_at, set_bit_at, toggle_bit_at,
+    read_bit_field, write_bit_field,
+    check_any_flag_u16, check_any_flag_u32, check_any_flag_u64, check_flags_u16,
+    check_flags_u32, check_flags_u64, clear_bit16, clear_bit32, clear_bit64, read_bit16,
+    read_bit32, read_bit64, set_bit16, set_bit32, set_bit64, toggle_bit16, toggle_bit32,
+    toggle_bit64,
+    count_set_bits, count_set_bits16, count_set_bits32, count_set_bits64, first_clear_bit,
+    first_clear_bit16, first_clear_bit32, first_clear_bit64,
 };
 pub use view::{validate_account, validate_account_mut, validate_foreign, load_unverified_overlay};
 #[cfg(not(feature = "strict"))]
@@ -215,6 +215,12 @@ macro_rules! zero_copy_layout {
             const SIZE: usize = 0 $( + $fsize )+;
         }
 
+        impl $crate::account::AccountType for $name {
+            const DISC: u8 = $disc;
+            const VERSION: u8 = $ver;
+            const LAYOUT_ID: [u8; 8] = $name::LAYOUT_ID;
+        }
+
         // Compile-time assertion: size_of must equal the declared LEN.
         // Catches cases where a field's declared byte size doesn't match
         // its actual Rust type size.
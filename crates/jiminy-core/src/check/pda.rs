@@ -18,7 +18,8 @@ use sha2_const_stable::Sha256;
 ///
 /// Uses the `sol_sha256` syscall directly - avoids the cost of
 /// `create_program_address` (~1500 CU) at the expense of no curve-point
-/// validation.
+/// validation. Off-chain it panics unless the `host-sim` feature is on,
+/// in which case it hashes in pure Rust.
 #[inline(always)]
 pub fn derive_address<const N: usize>(
     seeds: &[&[u8]; N],
@@ -72,7 +73,13 @@ pub fn derive_address<const N: usize>(
         unsafe { pda.assume_init() }
     }
 
-    #[cfg(not(target_os = "solana"))]
+    #[cfg(all(not(target_os = "solana"), feature = "host-sim"))]
+    {
+        let _ = data;
+        derive_address_const(seeds, bump, program_id)
+    }
+
+    #[cfg(all(not(target_os = "solana"), not(feature = "host-sim")))]
     {
         let _ = data;
         unreachable!("deriving a pda is only available on target `solana`");
@@ -112,6 +119,72 @@ pub const fn derive_address_const<const N: usize>(
     }
 }
 
+/// Find the canonical PDA and bump for `seeds` under `program_id`.
+///
+/// On-chain this is the `find_program_address` syscall. Off-chain it runs
+/// the same search in pure Rust (SHA-256 plus an ed25519 curve check per
+/// bump, starting at 255), so host tests get the exact address the
+/// runtime would. Panics if no bump yields an off-curve address.
+#[inline(always)]
+pub fn find_program_address(seeds: &[&[u8]], program_id: &Address) -> (Address, u8) {
+    #[cfg(target_os = "solana")]
+    {
+        Address::find_program_address(seeds, program_id)
+    }
+    #[cfg(not(target_os = "solana"))]
+    {
+        host::find_program_address(seeds, program_id)
+    }
+}
+
+/// `create_program_address`: hash `seeds` (bump included) and reject
+/// on-curve results with `InvalidSeeds`. Off-chain only with `host-sim`.
+#[cfg(any(target_os = "solana", feature = "host-sim"))]
+#[inline(always)]
+pub(crate) fn create_program_address(
+    seeds: &[&[u8]],
+    program_id: &Address,
+) -> Result<Address, ProgramError> {
+    #[cfg(target_os = "solana")]
+    {
+        Address::create_program_address(seeds, program_id).map_err(|_| ProgramError::InvalidSeeds)
+    }
+    #[cfg(not(target_os = "solana"))]
+    {
+        host::create_program_address(seeds, program_id)
+    }
+}
+
+/// Copy `seeds` into `buf` followed by `bump`, returning the filled prefix.
+///
+/// The `seeds ++ [bump]` shape that `create_program_address` and signed
+/// CPIs take. Fails with `InvalidSeeds` if the result would exceed the
+/// runtime's [`MAX_SEEDS`].
+///
+/// ```rust,ignore
+/// let bump = [state.bump];
+/// let mut buf = [&[][..]; MAX_SEEDS];
+/// let seeds = append_bump(&[b"vault", authority.as_ref()], &bump, &mut buf)?;
+/// ```
+#[inline(always)]
+pub fn append_bump<'a, 'b>(
+    seeds: &[&'a [u8]],
+    bump: &'a [u8; 1],
+    buf: &'b mut [&'a [u8]; MAX_SEEDS],
+) -> Result<&'b [&'a [u8]], ProgramError> {
+    let n = seeds.len();
+    if n >= MAX_SEEDS {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    let mut i = 0;
+    while i < n {
+        buf[i] = seeds[i];
+        i += 1;
+    }
+    buf[n] = bump;
+    Ok(&buf[..n + 1])
+}
+
 /// Derive the associated token account (ATA) address for a wallet + mint pair.
 #[cfg(feature = "programs")]
 #[inline(always)]
@@ -140,7 +213,12 @@ pub fn derive_ata_with_program(
         let (address, bump) = Address::find_program_address(seeds, &crate::programs::ASSOCIATED_TOKEN);
         Ok((address, bump))
     }
-    #[cfg(not(target_os = "solana"))]
+    #[cfg(all(not(target_os = "solana"), feature = "host-sim"))]
+    {
+        let seeds: &[&[u8]] = &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()];
+        Ok(find_program_address(seeds, &crate::programs::ASSOCIATED_TOKEN))
+    }
+    #[cfg(all(not(target_os = "solana"), not(feature = "host-sim")))]
     {
         let _ = (wallet, mint, token_program);
         Err(ProgramError::InvalidSeeds)
@@ -162,6 +240,44 @@ pub fn derive_ata_with_bump(
     ))
 }
 
+/// Derive the Metaplex metadata PDA for a mint.
+///
+/// Seeds are `["metadata", METADATA, mint]` under the Token Metadata program.
+#[cfg(feature = "programs")]
+#[inline(always)]
+pub fn derive_metadata_pda(mint: &Address) -> Result<(Address, u8), ProgramError> {
+    #[cfg(target_os = "solana")]
+    {
+        let seeds: &[&[u8]] = &[
+            b"metadata",
+            crate::programs::METADATA.as_ref(),
+            mint.as_ref(),
+        ];
+        Ok(Address::find_program_address(seeds, &crate::programs::METADATA))
+    }
+    #[cfg(all(not(target_os = "solana"), feature = "host-sim"))]
+    {
+        let seeds: &[&[u8]] = &[b"metadata", crate::programs::METADATA.as_ref(), mint.as_ref()];
+        Ok(find_program_address(seeds, &crate::programs::METADATA))
+    }
+    #[cfg(all(not(target_os = "solana"), not(feature = "host-sim")))]
+    {
+        let _ = mint;
+        Err(ProgramError::InvalidSeeds)
+    }
+}
+
+/// Derive the Metaplex metadata PDA with a known bump. Skips the bump search.
+#[cfg(feature = "programs")]
+#[inline(always)]
+pub fn derive_metadata_pda_with_bump(mint: &Address, bump: u8) -> Address {
+    Address::new_from_array(derive_address(
+        &[b"metadata", crate::programs::METADATA.as_array().as_ref(), mint.as_ref()],
+        Some(bump),
+        crate::programs::METADATA.as_array(),
+    ))
+}
+
 /// Derive an ATA address at compile time. Requires known bump.
 #[cfg(feature = "programs")]
 #[macro_export]
@@ -181,20 +297,14 @@ macro_rules! derive_ata_const {
 
 /// Find a PDA and return `(Address, u8)` with the canonical bump.
 ///
-/// Uses the `find_program_address` syscall. Only available on-chain.
+/// Uses the `find_program_address` syscall on-chain and the pure-Rust
+/// [`find_program_address`](crate::check::pda::find_program_address) search
+/// off-chain, so host tests can call it.
 #[macro_export]
 macro_rules! find_pda {
     ($program_id:expr, $($seed:expr),+ $(,)?) => {{
-        #[cfg(target_os = "solana")]
-        {
-            let seeds: &[&[u8]] = &[$($seed.as_ref()),+];
-            ::hopper_runtime::Address::find_program_address(seeds, $program_id)
-        }
-        #[cfg(not(target_os = "solana"))]
-        {
-            let _ = ($program_id, $($seed),+);
-            unreachable!("find_pda! is only available on target solana")
-        }
+        let seeds: &[&[u8]] = &[$($seed.as_ref()),+];
+        $crate::check::pda::find_program_address(seeds, $program_id)
     }};
 }
 
@@ -255,6 +365,27 @@ pub fn check_ata_with_program(
     Ok(())
 }
 
+/// Verify an account is the Metaplex metadata PDA for `mint` and return its bump.
+///
+/// Seeds are `["metadata", METADATA, mint]` under the Token Metadata
+/// program. Uses `find_program_address`; off-chain it needs `host-sim`.
+///
+/// ```rust,ignore
+/// let bump = check_metadata_pda(metadata, mint.address())?;
+/// ```
+#[cfg(feature = "programs")]
+#[inline(always)]
+pub fn check_metadata_pda(
+    account: &hopper_runtime::AccountView,
+    mint: &Address,
+) -> Result<u8, ProgramError> {
+    super::assert_pda(
+        account,
+        &[b"metadata", crate::programs::METADATA.as_ref(), mint.as_ref()],
+        &crate::programs::METADATA,
+    )
+}
+
 /// Derive a PDA from seeds, verify the account matches, and return the bump.
 ///
 /// Wraps [`assert_pda`](super::assert_pda) as a macro so you can pass
@@ -270,3 +401,263 @@ macro_rules! require_pda {
         $crate::check::assert_pda($account, seeds, $program_id)
     }};
 }
+
+/// Bind a PDA [`Signer`](hopper_runtime::cpi::Signer) for `invoke_signed`.
+///
+/// Appends the bump as the last seed and keeps the bump byte and the
+/// seed array alive in the caller's scope, which is the part that is
+/// easy to get wrong by hand. Seeds take anything that is `AsRef<[u8]>`,
+/// same as [`find_pda!`].
+///
+/// ```rust,ignore
+/// signer_seeds!(vault_signer = [b"vault", authority.address()], vault_bump);
+///
+/// TransferChecked {
+///     from: vault_tokens,
+///     mint,
+///     to: user_tokens,
+///     authority: vault,
+///     amount,
+///     decimals,
+/// }
+/// .invoke_signed(&[vault_signer])?;
+/// ```
+#[macro_export]
+macro_rules! signer_seeds {
+    ($signer:ident = [$($seed:expr),+ $(,)?], $bump:expr $(,)?) => {
+        let bump: [u8; 1] = [$bump];
+        let seeds = [
+            $( $crate::hopper_runtime::cpi::Seed::from({
+                let seed: &[u8] = $seed.as_ref();
+                seed
+            }), )+
+            $crate::hopper_runtime::cpi::Seed::from(&bump[..]),
+        ];
+        let $signer = $crate::hopper_runtime::cpi::Signer::from(&seeds[..]);
+    };
+}
+
+// ── Host-side derivation ─────────────────────────────────────────────────────
+
+/// Pure-Rust `find_program_address` for off-chain builds.
+///
+/// The curve check follows `curve25519-dalek` decompression: a 32-byte
+/// string is a point iff `(y^2 - 1) / (d*y^2 + 1)` is a square mod
+/// `2^255 - 19`, with `y` taken from the low 255 bits.
+#[cfg(not(target_os = "solana"))]
+mod host {
+    use super::{Address, Sha256, MAX_SEEDS, PDA_MARKER};
+
+    /// Field element mod `2^255 - 19`, four little-endian limbs.
+    type Fe = [u64; 4];
+
+    const P: Fe = [0xffff_ffff_ffff_ffed, u64::MAX, u64::MAX, 0x7fff_ffff_ffff_ffff];
+    /// Edwards `d = -121665 / 121666`.
+    const D: Fe = [
+        0x75eb_4dca_1359_78a3,
+        0x0070_0a4d_4141_d8ab,
+        0x8cc7_4079_7779_e898,
+        0x5203_6cee_2b6f_fe73,
+    ];
+    /// `(p - 1) / 2`, the Euler-criterion exponent.
+    const HALF_P_MINUS_1: Fe = [0xffff_ffff_ffff_fff6, u64::MAX, u64::MAX, 0x3fff_ffff_ffff_ffff];
+    const ONE: Fe = [1, 0, 0, 0];
+    const MAX_SEED_LEN: usize = 32;
+
+    pub fn find_program_address(seeds: &[&[u8]], program_id: &Address) -> (Address, u8) {
+        assert!(seeds.len() < MAX_SEEDS, "too many seeds");
+        let mut i = 0;
+        while i < seeds.len() {
+            assert!(seeds[i].len() <= MAX_SEED_LEN, "seed too long");
+            i += 1;
+        }
+
+        let mut bump = u8::MAX;
+        loop {
+            let mut hasher = Sha256::new();
+            let mut i = 0;
+            while i < seeds.len() {
+                hasher = hasher.update(seeds[i]);
+                i += 1;
+            }
+            let hash = hasher
+                .update(&[bump])
+                .update(program_id.as_ref())
+                .update(PDA_MARKER)
+                .finalize();
+            if !is_on_curve(&hash) {
+                return (Address::new_from_array(hash), bump);
+            }
+            assert!(bump > 0, "no viable bump seed");
+            bump -= 1;
+        }
+    }
+
+    #[cfg(feature = "host-sim")]
+    pub fn create_program_address(
+        seeds: &[&[u8]],
+        program_id: &Address,
+    ) -> Result<Address, hopper_runtime::ProgramError> {
+        if seeds.len() > MAX_SEEDS {
+            return Err(hopper_runtime::ProgramError::MaxSeedLengthExceeded);
+        }
+        let mut hasher = Sha256::new();
+        let mut i = 0;
+        while i < seeds.len() {
+            if seeds[i].len() > MAX_SEED_LEN {
+                return Err(hopper_runtime::ProgramError::MaxSeedLengthExceeded);
+            }
+            hasher = hasher.update(seeds[i]);
+            i += 1;
+        }
+        let hash = hasher.update(program_id.as_ref()).update(PDA_MARKER).finalize();
+        if is_on_curve(&hash) {
+            return Err(hopper_runtime::ProgramError::InvalidSeeds);
+        }
+        Ok(Address::new_from_array(hash))
+    }
+
+    fn is_on_curve(bytes: &[u8; 32]) -> bool {
+        let y = fe_from_bytes(bytes);
+        let y2 = mul(&y, &y);
+        let u = sub(&y2, &ONE);
+        let v = add(&mul(&D, &y2), &ONE);
+        // u/v is a square iff u*v is (v^2 is a nonzero square).
+        let chi = pow(&mul(&u, &v), &HALF_P_MINUS_1);
+        chi == ONE || chi == [0; 4]
+    }
+
+    fn fe_from_bytes(bytes: &[u8; 32]) -> Fe {
+        let mut fe = [0u64; 4];
+        let mut i = 0;
+        while i < 4 {
+            let mut limb = [0u8; 8];
+            limb.copy_from_slice(&bytes[i * 8..i * 8 + 8]);
+            fe[i] = u64::from_le_bytes(limb);
+            i += 1;
+        }
+        fe[3] &= 0x7fff_ffff_ffff_ffff;
+        reduce(fe)
+    }
+
+    /// Subtract `P` while `a >= P`. Inputs are below `2P`.
+    fn reduce(mut a: Fe) -> Fe {
+        if !lt(&a, &P) {
+            a = sub_raw(&a, &P).0;
+        }
+        a
+    }
+
+    fn lt(a: &Fe, b: &Fe) -> bool {
+        let mut i = 4;
+        while i > 0 {
+            i -= 1;
+            if a[i] != b[i] {
+                return a[i] < b[i];
+            }
+        }
+        false
+    }
+
+    fn sub_raw(a: &Fe, b: &Fe) -> (Fe, bool) {
+        let mut out = [0u64; 4];
+        let mut borrow = false;
+        let mut i = 0;
+        while i < 4 {
+            let (d1, b1) = a[i].overflowing_sub(b[i]);
+            let (d2, b2) = d1.overflowing_sub(borrow as u64);
+            out[i] = d2;
+            borrow = b1 || b2;
+            i += 1;
+        }
+        (out, borrow)
+    }
+
+    fn add_raw(a: &Fe, b: &Fe) -> Fe {
+        let mut out = [0u64; 4];
+        let mut carry = 0u128;
+        let mut i = 0;
+        while i < 4 {
+            let cur = a[i] as u128 + b[i] as u128 + carry;
+            out[i] = cur as u64;
+            carry = cur >> 64;
+            i += 1;
+        }
+        out
+    }
+
+    fn add(a: &Fe, b: &Fe) -> Fe {
+        // Both < 2^255, so the raw sum cannot overflow 256 bits.
+        reduce(add_raw(a, b))
+    }
+
+    fn sub(a: &Fe, b: &Fe) -> Fe {
+        let (diff, borrow) = sub_raw(a, b);
+        if borrow {
+            add_raw(&diff, &P)
+        } else {
+            diff
+        }
+    }
+
+    fn mul(a: &Fe, b: &Fe) -> Fe {
+        let mut t = [0u64; 8];
+        let mut i = 0;
+        while i < 4 {
+            let mut carry = 0u128;
+            let mut j = 0;
+            while j < 4 {
+                let cur = t[i + j] as u128 + a[i] as u128 * b[j] as u128 + carry;
+                t[i + j] = cur as u64;
+                carry = cur >> 64;
+                j += 1;
+            }
+            t[i + 4] = carry as u64;
+            i += 1;
+        }
+
+        // 2^256 = 38 (mod p): fold the high half into the low half.
+        let mut r = [0u64; 4];
+        let mut carry = 0u128;
+        let mut i = 0;
+        while i < 4 {
+            let cur = t[i] as u128 + t[i + 4] as u128 * 38 + carry;
+            r[i] = cur as u64;
+            carry = cur >> 64;
+            i += 1;
+        }
+        let mut carry = carry * 38;
+        while carry != 0 {
+            let mut i = 0;
+            while i < 4 {
+                let cur = r[i] as u128 + carry;
+                r[i] = cur as u64;
+                carry = cur >> 64;
+                i += 1;
+            }
+            carry *= 38;
+        }
+
+        // 2^255 = 19 (mod p): fold bit 255, leaving r < 2p.
+        let top = r[3] >> 63;
+        r[3] &= 0x7fff_ffff_ffff_ffff;
+        reduce(add_raw(&r, &[top * 19, 0, 0, 0]))
+    }
+
+    fn pow(base: &Fe, exp: &Fe) -> Fe {
+        let mut acc = ONE;
+        let mut limb = 4;
+        while limb > 0 {
+            limb -= 1;
+            let mut bit = 64;
+            while bit > 0 {
+                bit -= 1;
+                acc = mul(&acc, &acc);
+                if (exp[limb] >> bit) & 1 == 1 {
+                    acc = mul(&acc, base);
+                }
+            }
+        }
+        acc
+    }
+}
@@ -0,0 +1,104 @@
+//! Granular error codes for the built-in checks.
+//!
+//! By default every check fails with a stock [`ProgramError`] variant, so
+//! "wrong discriminator" and "closed account" both surface as
+//! `InvalidAccountData`. With the `granular-errors` feature the checks
+//! listed on each [`JiminyError`] variant return
+//! `ProgramError::Custom(code)` instead, and the code in the transaction
+//! log names the check that failed.
+//!
+//! Checks whose stock variant is already specific (signer, owner, PDA
+//! seeds, already-initialized) keep it either way.
+//!
+//! ```rust,ignore
+//! // jiminy-core = { version = "0.17", features = ["granular-errors"] }
+//! let err = check_discriminator(&data, VAULT_DISC).unwrap_err();
+//! assert_eq!(err, JiminyError::WrongDiscriminator.into());
+//! ```
+
+use hopper_runtime::ProgramError;
+
+/// First custom code used by [`JiminyError`].
+///
+/// `0x4A4D` ("JM") in the high half keeps the codes clear of Anchor's
+/// 6000+ range and of small `error_codes!` bases.
+pub const JIMINY_ERROR_BASE: u32 = 0x4A4D_0000;
+
+/// Specific failure reasons for the built-in checks.
+///
+/// Converts into `ProgramError::Custom(JIMINY_ERROR_BASE + n)`. The codes
+/// are part of the ABI: variants are only ever appended.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JiminyError {
+    /// First data byte is not the expected discriminator.
+    /// Plain: `InvalidAccountData`.
+    WrongDiscriminator = JIMINY_ERROR_BASE,
+    /// Account data is shorter than the layout needs.
+    /// Plain: `AccountDataTooSmall`.
+    AccountTooSmall,
+    /// Lamports below the rent-exempt minimum for the data length.
+    /// Plain: `InsufficientFunds`.
+    NotRentExempt,
+    /// Lamports below a required amount. Plain: `InsufficientFunds`.
+    InsufficientLamports,
+    /// Two account slots that must differ hold the same address.
+    /// Plain: `InvalidArgument`.
+    SameAccount,
+    /// Account is not marked writable. Plain: `InvalidArgument`.
+    NotWritable,
+    /// Address does not match the expected or stored key.
+    /// Plain: `InvalidArgument`.
+    KeyMismatch,
+    /// Header version is below the required minimum.
+    /// Plain: `InvalidAccountData`.
+    VersionTooLow,
+    /// Account still holds lamports or data. Plain: `InvalidAccountData`.
+    NotClosed,
+}
+
+impl JiminyError {
+    /// The `ProgramError::Custom` code for this variant.
+    #[inline(always)]
+    pub const fn code(self) -> u32 {
+        self as u32
+    }
+
+    /// Map a custom code back to its variant, if it is one of ours.
+    pub const fn from_code(code: u32) -> Option<Self> {
+        Some(match code.wrapping_sub(JIMINY_ERROR_BASE) {
+            0 => Self::WrongDiscriminator,
+            1 => Self::AccountTooSmall,
+            2 => Self::NotRentExempt,
+            3 => Self::InsufficientLamports,
+            4 => Self::SameAccount,
+            5 => Self::NotWritable,
+            6 => Self::KeyMismatch,
+            7 => Self::VersionTooLow,
+            8 => Self::NotClosed,
+            _ => return None,
+        })
+    }
+}
+
+impl From<JiminyError> for ProgramError {
+    #[inline(always)]
+    fn from(e: JiminyError) -> Self {
+        ProgramError::Custom(e.code())
+    }
+}
+
+/// Pick the granular or the plain error depending on `granular-errors`.
+#[inline(always)]
+pub(crate) fn fail(granular: JiminyError, plain: ProgramError) -> ProgramError {
+    #[cfg(feature = "granular-errors")]
+    {
+        let _ = plain;
+        granular.into()
+    }
+    #[cfg(not(feature = "granular-errors"))]
+    {
+        let _ = granular;
+        plain
+    }
+}
@@ -56,6 +56,26 @@ pub fn program_id_at(data: &[u8], index: u16) -> Result<Address, ProgramError> {
     Ok(Address::new_from_array(out))
 }
 
+/// Read the program_id of the instruction `relative` positions from the
+/// current one (`-1` is the instruction just before this one).
+///
+/// Returns `InvalidAccountData` if the target index falls outside the
+/// transaction.
+///
+/// ```rust,ignore
+/// // The ed25519 precompile must run immediately before this instruction.
+/// let ixs = instructions_sysvar.try_borrow()?;
+/// require_keys_eq!(sibling_program_id(&ixs, -1)?, programs::ED25519, MyError::NoSigCheck);
+/// ```
+#[inline(always)]
+pub fn sibling_program_id(data: &[u8], relative: i16) -> Result<Address, ProgramError> {
+    let target = current_index(data)? as i32 + relative as i32;
+    if target < 0 || target > u16::MAX as i32 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    program_id_at(data, target as u16)
+}
+
 /// Read the instruction data range (offset, length) for instruction at `index`.
 #[inline(always)]
 pub fn instruction_data_range(
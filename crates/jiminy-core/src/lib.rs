@@ -19,6 +19,8 @@
 //! | [`account`] | Header, reader, writer, cursor, lifecycle, pod, overlay, collection, list, bits |
 //! | [`abi`] | Alignment-1 LE field types (`LeU64`, `LeBool`, …) and borrow-splitting refs |
 //! | [`check`] | Validation checks, asserts, PDA derivation & verification |
+//! | [`signer`] | `PdaSigner`: PDA seeds with an owned bump for signed CPIs |
+//! | [`error`] | `JiminyError` codes for the checks *(feature: `granular-errors`)* |
 //! | [`compat`] | Optional `solana-zero-copy` integration *(feature: `solana-zero-copy`)* |
 //! | [`instruction`] | Transaction introspection (sysvar Instructions) |
 //! | [`interface`] | Cross-program ABI interfaces (`jiminy_interface!`) |
@@ -39,11 +41,24 @@
 //! | Macro | |
 //! |---|---|
 //! | [`require!`] | `if !cond { return Err(e) }` -- the universal guard |
+//! | [`require_msg!`] | [`require!`] that logs a message on failure *(feature: `log`)* |
+//! | [`require_some!`] | Unwrap an `Option` or return the error |
+//! | [`checked_require_sub!`] | Require `a >= b` and evaluate to `a - b` |
 //! | [`require_keys_eq!`] | Two owned or borrowed `Address` operands must match |
 //! | [`require_keys_neq!`] | Two owned or borrowed `Address` operands must differ |
+//! | [`require_account_key_eq!`] | An account's address must equal an `Address` |
+//! | [`require_account_key_neq!`] | An account's address must differ from an `Address` |
+//! | [`require_keys_in!`] | `Address` must be one of an allowlist |
+//! | [`require_signer!`] / [`require_writable!`] / [`require_owner!`] | Signer / writable / owner check with a custom error |
+//! | [`require_rent_exempt!`] / [`require_lamports_gte!`] | Funding checks with a custom error |
 //! | [`check_accounts_unique!`] | Pairwise uniqueness for any N accounts |
 //! | [`error_codes!`] | Define numbered error codes without a proc macro |
+//! | [`disc_of!`] | Compile-time 1-byte discriminator from a type name |
+//! | [`unique_discriminators!`] | Compile-time check that discriminators don't collide |
+//! | [`static_assert!`] | Compile-time check on layout constants |
+//! | [`flags!`] | Typed bit-flag set with mask constants over a backing integer |
 //! | [`instruction_dispatch!`] | Byte-tag instruction routing |
+//! | [`dispatch!`] | Byte-tag routing to same-signature handlers |
 //! | [`jiminy_interface!`](crate::jiminy_interface) | Read-only interface for foreign program accounts |
 //! | [`impl_pod!`] | Batch `unsafe impl Pod` |
 //! | [`assert_legacy_layout!`] | Validate existing non-Jiminy account ABIs without adding a header |
@@ -59,12 +74,14 @@
 
 pub mod account;
 pub mod check;
+pub mod error;
 pub mod event;
 pub mod field;
 pub mod instruction;
 pub mod math;
 pub mod packed;
 pub mod prelude;
+pub mod signer;
 pub mod state;
 pub mod state_utils;
 pub mod sysvar;
@@ -99,18 +116,113 @@ pub const fn __sha256_const(data: &[u8]) -> [u8; 32] {
     sha2_const_stable::Sha256::new().update(data).finalize()
 }
 
+/// Const helper for `disc_of!`: first non-zero byte of `sha256(name)`.
+#[doc(hidden)]
+pub const fn __disc_of(name: &str) -> u8 {
+    let hash = __sha256_const(name.as_bytes());
+    let mut i = 0;
+    while i < 32 {
+        if hash[i] != 0 {
+            return hash[i];
+        }
+        i += 1;
+    }
+    // Unreachable for any real name: all 32 bytes would have to be zero.
+    1
+}
+
+/// Log hook for `require_msg!`. A no-op unless the `log` feature is on.
+#[doc(hidden)]
+#[inline(always)]
+pub fn __log_failure(msg: &str) {
+    #[cfg(feature = "log")]
+    log::log_msg(msg);
+    #[cfg(not(feature = "log"))]
+    let _ = msg;
+}
+
+/// Failure hook for the `require_*` family: logs the `file:line` of the
+/// failing check. A no-op unless the `log-on-failure` feature is on.
+#[doc(hidden)]
+#[inline(always)]
+pub fn __require_failed(site: &str) {
+    #[cfg(feature = "log-on-failure")]
+    log::log_msg(site);
+    #[cfg(not(feature = "log-on-failure"))]
+    let _ = site;
+}
+
+/// Const helper for `unique_discriminators!`: `true` if no byte repeats.
+#[doc(hidden)]
+pub const fn __all_unique(discs: &[u8]) -> bool {
+    let mut i = 0;
+    while i < discs.len() {
+        let mut j = i + 1;
+        while j < discs.len() {
+            if discs[i] == discs[j] {
+                return false;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    true
+}
+
 // ── Macros ───────────────────────────────────────────────────────────────────
 
 /// Require a boolean condition: return `$err` (converted via `Into`) if false.
+///
+/// With the `log-on-failure` feature, this and every other `require_*`
+/// macro log the `file:line` of the failing check before returning.
 #[macro_export]
 macro_rules! require {
     ($cond:expr, $err:expr $(,)?) => {
         if !($cond) {
+            $crate::__require_failed(concat!(file!(), ":", line!()));
+            return Err($err.into());
+        }
+    };
+}
+
+/// [`require!`] that logs `$msg` before returning `$err`.
+///
+/// The message goes to the program log when the `log` feature is enabled
+/// and compiles away otherwise, so the happy path costs nothing either way.
+///
+/// ```rust,ignore
+/// require_msg!(amount > 0, MyError::ZeroAmount, "deposit: amount is zero");
+/// ```
+#[macro_export]
+macro_rules! require_msg {
+    ($cond:expr, $err:expr, $msg:expr $(,)?) => {
+        if !($cond) {
+            $crate::__log_failure($msg);
             return Err($err.into());
         }
     };
 }
 
+/// Unwrap an `Option`: evaluate to the inner value, or return `$err` if `None`.
+///
+/// The `Option` counterpart to [`require!`].
+///
+/// ```rust,ignore
+/// let delegate = require_some!(token_account_delegate(acc)?, MyError::NoDelegate);
+/// ```
+#[macro_export]
+macro_rules! require_some {
+    ($opt:expr, $err:expr $(,)?) => {
+        match $opt {
+            Some(__jiminy_value) => __jiminy_value,
+            None => {
+                $crate::__require_failed(concat!(file!(), ":", line!()));
+                return Err($err.into());
+            }
+        }
+    };
+}
+
 /// Require two [`Address`] values to be equal.
 ///
 /// Accepts owned `Address` values, `&Address` references, or a mix of both.
@@ -120,6 +232,7 @@ macro_rules! require_keys_eq {
         let __jiminy_a: &$crate::Address = &$a;
         let __jiminy_b: &$crate::Address = &$b;
         if __jiminy_a != __jiminy_b {
+            $crate::__require_failed(concat!(file!(), ":", line!()));
             return Err($err.into());
         }
     };
@@ -134,6 +247,75 @@ macro_rules! require_keys_neq {
         let __jiminy_a: &$crate::Address = &$a;
         let __jiminy_b: &$crate::Address = &$b;
         if __jiminy_a == __jiminy_b {
+            $crate::__require_failed(concat!(file!(), ":", line!()));
+            return Err($err.into());
+        }
+    };
+}
+
+/// Require an account's address to equal `$expected`.
+///
+/// Account-side counterpart of [`require_keys_eq!`]: calls `.address()`
+/// on `$account`, so `$expected` is the only operand to spell out.
+///
+/// ```rust,ignore
+/// require_account_key_eq!(vault, &state.vault, MyError::WrongVault);
+/// ```
+#[macro_export]
+macro_rules! require_account_key_eq {
+    ($account:expr, $expected:expr, $err:expr $(,)?) => {
+        let __jiminy_expected: &$crate::Address = &$expected;
+        if $account.address() != __jiminy_expected {
+            $crate::__require_failed(concat!(file!(), ":", line!()));
+            return Err($err.into());
+        }
+    };
+}
+
+/// Require an account's address to differ from `$other`.
+///
+/// Account-side counterpart of [`require_keys_neq!`].
+#[macro_export]
+macro_rules! require_account_key_neq {
+    ($account:expr, $other:expr, $err:expr $(,)?) => {
+        let __jiminy_other: &$crate::Address = &$other;
+        if $account.address() == __jiminy_other {
+            $crate::__require_failed(concat!(file!(), ":", line!()));
+            return Err($err.into());
+        }
+    };
+}
+
+/// Require `$account` to be owned by `$program_id`, else return `$err`.
+///
+/// Like [`check_owner`](crate::check::check_owner), but with a caller-chosen
+/// error instead of `IncorrectProgramId`. `$program_id` may be an owned
+/// `Address` or a reference.
+#[macro_export]
+macro_rules! require_owner {
+    ($account:expr, $program_id:expr, $err:expr $(,)?) => {
+        let __jiminy_owner: &$crate::Address = &$program_id;
+        if !$account.owned_by(__jiminy_owner) {
+            $crate::__require_failed(concat!(file!(), ":", line!()));
+            return Err($err.into());
+        }
+    };
+}
+
+/// Require `$key` to equal one of the addresses in `$keys`.
+///
+/// For allowlists of authorities or accepted mints:
+///
+/// ```rust,ignore
+/// require_keys_in!(mint.address(), &[USDC_MINT, USDT_MINT], MyError::MintNotAccepted);
+/// ```
+#[macro_export]
+macro_rules! require_keys_in {
+    ($key:expr, $keys:expr, $err:expr $(,)?) => {
+        let __jiminy_key: &$crate::Address = &$key;
+        let __jiminy_keys: &[$crate::Address] = $keys;
+        if !__jiminy_keys.contains(__jiminy_key) {
+            $crate::__require_failed(concat!(file!(), ":", line!()));
             return Err($err.into());
         }
     };
@@ -144,6 +326,65 @@ macro_rules! require_keys_neq {
 macro_rules! require_accounts_ne {
     ($a:expr, $b:expr, $err:expr $(,)?) => {
         if $a.address() == $b.address() {
+            $crate::__require_failed(concat!(file!(), ":", line!()));
+            return Err($err.into());
+        }
+    };
+}
+
+/// Require `$account` to have signed the transaction, else return `$err`.
+///
+/// Like [`check_signer`](crate::check::check_signer), but with a caller-chosen
+/// error instead of `MissingRequiredSignature`.
+#[macro_export]
+macro_rules! require_signer {
+    ($account:expr, $err:expr $(,)?) => {
+        if !$account.is_signer() {
+            $crate::__require_failed(concat!(file!(), ":", line!()));
+            return Err($err.into());
+        }
+    };
+}
+
+/// Require `$account` to be writable, else return `$err`.
+///
+/// Like [`check_writable`](crate::check::check_writable), but with a
+/// caller-chosen error instead of `InvalidArgument`.
+#[macro_export]
+macro_rules! require_writable {
+    ($account:expr, $err:expr $(,)?) => {
+        if !$account.is_writable() {
+            $crate::__require_failed(concat!(file!(), ":", line!()));
+            return Err($err.into());
+        }
+    };
+}
+
+/// Require `$account` to hold enough lamports to be rent-exempt for its
+/// current data length, else return `$err`.
+///
+/// Like [`check_rent_exempt`](crate::check::check_rent_exempt), but with a
+/// caller-chosen error instead of `InsufficientFunds`.
+#[macro_export]
+macro_rules! require_rent_exempt {
+    ($account:expr, $err:expr $(,)?) => {{
+        let account = &$account;
+        if account.lamports() < $crate::check::rent_exempt_min(account.data_len()) {
+            $crate::__require_failed(concat!(file!(), ":", line!()));
+            return Err($err.into());
+        }
+    }};
+}
+
+/// Require `$account` to hold at least `$lamports`, else return `$err`.
+///
+/// Like [`check_lamports_gte`](crate::check::check_lamports_gte), but with
+/// a caller-chosen error.
+#[macro_export]
+macro_rules! require_lamports_gte {
+    ($account:expr, $lamports:expr, $err:expr $(,)?) => {
+        if $account.lamports() < $lamports {
+            $crate::__require_failed(concat!(file!(), ":", line!()));
             return Err($err.into());
         }
     };
@@ -154,6 +395,7 @@ macro_rules! require_accounts_ne {
 macro_rules! require_gte {
     ($a:expr, $b:expr, $err:expr $(,)?) => {
         if $a < $b {
+            $crate::__require_failed(concat!(file!(), ":", line!()));
             return Err($err.into());
         }
     };
@@ -164,6 +406,7 @@ macro_rules! require_gte {
 macro_rules! require_gt {
     ($a:expr, $b:expr, $err:expr $(,)?) => {
         if $a <= $b {
+            $crate::__require_failed(concat!(file!(), ":", line!()));
             return Err($err.into());
         }
     };
@@ -174,6 +417,7 @@ macro_rules! require_gt {
 macro_rules! require_lt {
     ($a:expr, $b:expr, $err:expr $(,)?) => {
         if $a >= $b {
+            $crate::__require_failed(concat!(file!(), ":", line!()));
             return Err($err.into());
         }
     };
@@ -184,16 +428,39 @@ macro_rules! require_lt {
 macro_rules! require_lte {
     ($a:expr, $b:expr, $err:expr $(,)?) => {
         if $a > $b {
+            $crate::__require_failed(concat!(file!(), ":", line!()));
             return Err($err.into());
         }
     };
 }
 
+/// Require `a >= b` and evaluate to `a - b`, else return `$err`.
+///
+/// The "ensure enough, then subtract" step as one expression. Each
+/// operand is evaluated once.
+///
+/// ```rust,ignore
+/// let remaining = checked_require_sub!(balance, amount, ProgramError::InsufficientFunds);
+/// ```
+#[macro_export]
+macro_rules! checked_require_sub {
+    ($a:expr, $b:expr, $err:expr $(,)?) => {{
+        let a = $a;
+        let b = $b;
+        if a < b {
+            $crate::__require_failed(concat!(file!(), ":", line!()));
+            return Err($err.into());
+        }
+        a - b
+    }};
+}
+
 /// Require `a == b` for scalar types.
 #[macro_export]
 macro_rules! require_eq {
     ($a:expr, $b:expr, $err:expr $(,)?) => {
         if $a != $b {
+            $crate::__require_failed(concat!(file!(), ":", line!()));
             return Err($err.into());
         }
     };
@@ -204,6 +471,7 @@ macro_rules! require_eq {
 macro_rules! require_neq {
     ($a:expr, $b:expr, $err:expr $(,)?) => {
         if $a == $b {
+            $crate::__require_failed(concat!(file!(), ":", line!()));
             return Err($err.into());
         }
     };
@@ -214,11 +482,119 @@ macro_rules! require_neq {
 macro_rules! require_flag {
     ($byte:expr, $n:expr, $err:expr $(,)?) => {
         if ($byte >> $n) & 1 == 0 {
+            $crate::__require_failed(concat!(file!(), ":", line!()));
             return Err($err.into());
         }
     };
 }
 
+/// Declare a typed set of bit flags over a backing integer.
+///
+/// Each flag is given by its bit *index*; the macro turns it into a
+/// typed mask constant, so an index can never be passed where a mask is
+/// expected (or vice versa). The wrapper is `#[repr(transparent)]` and
+/// every method is `const` or `#[inline(always)]`.
+///
+/// ```rust,ignore
+/// flags! {
+///     /// Escrow lifecycle flags.
+///     pub struct EscrowFlags: u8 {
+///         ACCEPTED = 0,
+///         CANCELLED = 1,
+///     }
+/// }
+///
+/// let mut flags = EscrowFlags::from_bits(read_flags_at(&data, 2)?);
+/// require!(!flags.contains(EscrowFlags::ACCEPTED), ProgramError::InvalidAccountData);
+/// flags.insert(EscrowFlags::ACCEPTED);
+/// write_flags_at(&mut data, 2, flags.bits())?;
+/// ```
+#[macro_export]
+macro_rules! flags {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident : $ty:ty {
+            $( $(#[$fmeta:meta])* $flag:ident = $bit:expr ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+        #[repr(transparent)]
+        $vis struct $name($ty);
+
+        #[allow(dead_code)]
+        impl $name {
+            $(
+                $(#[$fmeta])*
+                pub const $flag: Self = Self(1 << $bit);
+            )*
+
+            /// Every declared flag.
+            pub const ALL: Self = Self(0 $( | (1 << $bit) )*);
+
+            /// No flags set.
+            #[inline(always)]
+            pub const fn empty() -> Self {
+                Self(0)
+            }
+
+            /// Wrap a raw value read from account data.
+            #[inline(always)]
+            pub const fn from_bits(bits: $ty) -> Self {
+                Self(bits)
+            }
+
+            /// The raw value, for writing back to account data.
+            #[inline(always)]
+            pub const fn bits(self) -> $ty {
+                self.0
+            }
+
+            /// `true` if every flag in `other` is set.
+            #[inline(always)]
+            pub const fn contains(self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// `true` if any flag in `other` is set.
+            #[inline(always)]
+            pub const fn intersects(self, other: Self) -> bool {
+                self.0 & other.0 != 0
+            }
+
+            /// `true` if no bits outside the declared flags are set.
+            #[inline(always)]
+            pub const fn is_known(self) -> bool {
+                self.0 & !Self::ALL.0 == 0
+            }
+
+            /// Combine two flag sets.
+            #[inline(always)]
+            pub const fn union(self, other: Self) -> Self {
+                Self(self.0 | other.0)
+            }
+
+            /// Set the flags in `other`.
+            #[inline(always)]
+            pub fn insert(&mut self, other: Self) {
+                self.0 |= other.0;
+            }
+
+            /// Clear the flags in `other`.
+            #[inline(always)]
+            pub fn remove(&mut self, other: Self) {
+                self.0 &= !other.0;
+            }
+
+            /// Flip the flags in `other`.
+            #[inline(always)]
+            pub fn toggle(&mut self, other: Self) {
+                self.0 ^= other.0;
+            }
+        }
+    };
+}
+
 /// Verify that all passed accounts have unique addresses.
 ///
 /// Variadic - works with 2, 3, 4, or more accounts. Expands to
@@ -303,6 +679,65 @@ macro_rules! error_codes {
     };
 }
 
+/// Derive a 1-byte account discriminator from a type name at compile time.
+///
+/// Takes the first non-zero byte of `sha256(name)`, so the result is
+/// stable across builds and never collides with zero-filled data.
+///
+/// One byte only gives 255 values. With `n` account types the chance of
+/// at least one clash is about `1 - e^(-n(n-1)/510)`: ~4% for 5 types,
+/// ~16% for 10, ~52% for 20. Pair with [`unique_discriminators!`] so a
+/// clash fails the build instead of aliasing two layouts.
+///
+/// ```rust,ignore
+/// const VAULT_DISC: u8 = disc_of!("Vault");
+/// const POOL_DISC: u8 = disc_of!("Pool");
+/// unique_discriminators!(VAULT_DISC, POOL_DISC);
+/// ```
+#[macro_export]
+macro_rules! disc_of {
+    ($name:expr $(,)?) => {
+        $crate::__disc_of($name)
+    };
+}
+
+/// Compile-time check that every listed discriminator is distinct.
+///
+/// Expands to a `const` assertion, so a duplicate is a build error.
+///
+/// ```rust,ignore
+/// unique_discriminators!(Vault::DISC, Pool::DISC, Position::DISC);
+/// ```
+#[macro_export]
+macro_rules! unique_discriminators {
+    ($($disc:expr),+ $(,)?) => {
+        const _: () = assert!(
+            $crate::__all_unique(&[$($disc),+]),
+            "duplicate account discriminator"
+        );
+    };
+}
+
+/// Compile-time assertion for layout constants.
+///
+/// Expands to a `const` assertion, so a false condition is a build error
+/// rather than a mis-sized account found at runtime. Usable at item level
+/// or inside a function.
+///
+/// ```rust,ignore
+/// static_assert!(VAULT_LEN == HEADER_LEN + 8 + 32);
+/// static_assert!(BUMP_OFFSET + 1 <= VAULT_LEN, "bump outside the account");
+/// ```
+#[macro_export]
+macro_rules! static_assert {
+    ($cond:expr $(,)?) => {
+        const _: () = assert!($cond, "{}", concat!("static assertion failed: ", stringify!($cond)));
+    };
+    ($cond:expr, $msg:literal $(,)?) => {
+        const _: () = assert!($cond, $msg);
+    };
+}
+
 /// Route instruction data to handler functions based on a single-byte tag.
 ///
 /// Replaces Anchor's `#[program]` proc macro. Reads byte 0 as the
@@ -312,15 +747,35 @@ macro_rules! error_codes {
 /// ```rust,ignore
 /// instruction_dispatch! {
 ///     program_id, accounts, instruction_data;
-///     0 => process_init(program_id, accounts, ix),
-///     1 => process_deposit(program_id, accounts, ix),
-///     2 => process_withdraw(program_id, accounts, ix),
+///     0 => process_init(program_id, accounts),
+///     1 => process_close(program_id, accounts),
 /// }
 /// ```
 ///
-/// Inside each arm, `ix` is a `SliceCursor` positioned after the tag byte.
+/// To read arguments, name the cursor after the data. It is positioned
+/// after the tag, and tags may be any pattern (`const` tags, ranges):
+///
+/// ```rust,ignore
+/// instruction_dispatch! {
+///     program_id, accounts, instruction_data; ix;
+///     IX_INIT => process_init(program_id, accounts, &ix),
+///     IX_CLOSE => process_close(program_id, accounts),
+/// }
+/// ```
+///
+/// When every handler has the same signature, [`dispatch!`] is shorter.
 #[macro_export]
 macro_rules! instruction_dispatch {
+    (
+        $pid:expr, $accs:expr, $data:expr; $ix:ident;
+        $( $tag:pat => $handler:expr ),+ $(,)?
+    ) => {{
+        let mut $ix = $crate::account::SliceCursor::new($data);
+        match $ix.read_u8()? {
+            $( $tag => { let _ = &$ix; $handler } )+
+            _ => Err($crate::ProgramError::InvalidInstructionData),
+        }
+    }};
     (
         $pid:expr, $accs:expr, $data:expr;
         $( $tag:expr => $handler:expr ),+ $(,)?
@@ -334,6 +789,34 @@ macro_rules! instruction_dispatch {
     }};
 }
 
+/// Route instruction data to handlers that all take
+/// `(program_id, accounts, &SliceCursor)`.
+///
+/// [`instruction_dispatch!`] with the calls written for you: each handler
+/// gets the cursor positioned after the tag byte. Unknown tags return
+/// `InvalidInstructionData`. Tags are patterns.
+///
+/// ```rust,ignore
+/// dispatch! {
+///     program_id, accounts, instruction_data;
+///     IX_INIT => process_init,
+///     IX_DEPOSIT => process_deposit,
+/// }
+/// ```
+#[macro_export]
+macro_rules! dispatch {
+    (
+        $pid:expr, $accs:expr, $data:expr;
+        $( $tag:pat => $handler:path ),+ $(,)?
+    ) => {{
+        let mut ix = $crate::account::SliceCursor::new($data);
+        match ix.read_u8()? {
+            $( $tag => $handler($pid, $accs, &ix), )+
+            _ => Err($crate::ProgramError::InvalidInstructionData),
+        }
+    }};
+}
+
 /// Initialize a Jiminy account: CPI CreateAccount, zero-init, write header.
 ///
 /// Owns the full creation path so developers cannot forget zero_init or
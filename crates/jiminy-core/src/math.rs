@@ -18,6 +18,52 @@ pub fn checked_mul(a: u64, b: u64) -> Result<u64, ProgramError> {
     a.checked_mul(b).ok_or(ProgramError::ArithmeticOverflow)
 }
 
+/// Checked i64 addition: returns `ArithmeticOverflow` on overflow.
+///
+/// For signed timestamp math such as `now + grace_period`.
+#[inline(always)]
+pub fn checked_add_i64(a: i64, b: i64) -> Result<i64, ProgramError> {
+    a.checked_add(b).ok_or(ProgramError::ArithmeticOverflow)
+}
+
+/// Checked i64 subtraction: returns `ArithmeticOverflow` on overflow.
+#[inline(always)]
+pub fn checked_sub_i64(a: i64, b: i64) -> Result<i64, ProgramError> {
+    a.checked_sub(b).ok_or(ProgramError::ArithmeticOverflow)
+}
+
+/// Checked i64 multiplication: returns `ArithmeticOverflow` on overflow.
+#[inline(always)]
+pub fn checked_mul_i64(a: i64, b: i64) -> Result<i64, ProgramError> {
+    a.checked_mul(b).ok_or(ProgramError::ArithmeticOverflow)
+}
+
+/// Saturating u64 addition: clamps at `u64::MAX`.
+///
+/// For stats and telemetry counters, where a pinned counter is better
+/// than a failed instruction. Balances and amounts stay on the checked
+/// functions.
+///
+/// ```rust,ignore
+/// stats.swaps = saturating_add(stats.swaps, 1);
+/// ```
+#[inline(always)]
+pub fn saturating_add(a: u64, b: u64) -> u64 {
+    a.saturating_add(b)
+}
+
+/// Saturating u64 subtraction: clamps at 0.
+#[inline(always)]
+pub fn saturating_sub(a: u64, b: u64) -> u64 {
+    a.saturating_sub(b)
+}
+
+/// Saturating u64 multiplication: clamps at `u64::MAX`.
+#[inline(always)]
+pub fn saturating_mul(a: u64, b: u64) -> u64 {
+    a.saturating_mul(b)
+}
+
 /// Checked u64 division: returns `ArithmeticOverflow` on divide-by-zero.
 ///
 /// Every AMM price calculation involves division. This is the missing
@@ -31,6 +77,20 @@ pub fn checked_div(a: u64, b: u64) -> Result<u64, ProgramError> {
     a.checked_div(b).ok_or(ProgramError::ArithmeticOverflow)
 }
 
+/// Checked u64 remainder: returns `ArithmeticOverflow` on divide-by-zero.
+///
+/// Pairs with `checked_div` when splitting an amount and the leftover
+/// dust has to go somewhere explicit.
+///
+/// ```rust,ignore
+/// let per_user = checked_div(pool, users)?;
+/// let dust = checked_rem(pool, users)?;
+/// ```
+#[inline(always)]
+pub fn checked_rem(a: u64, b: u64) -> Result<u64, ProgramError> {
+    a.checked_rem(b).ok_or(ProgramError::ArithmeticOverflow)
+}
+
 /// Checked ceiling division: `ceil(a / b)`. Returns `ArithmeticOverflow` on zero.
 ///
 /// Rounds up instead of truncating. Use this for fee calculations and
@@ -55,7 +115,8 @@ pub fn checked_div_ceil(a: u64, b: u64) -> Result<u64, ProgramError> {
 ///
 /// **The single most important DeFi math primitive.** Without u128
 /// intermediate, `a * b` overflows for any token amounts > ~4.2B.
-/// Returns floor division.
+/// Returns floor division. Fails with `ArithmeticOverflow` if `c == 0`
+/// or the quotient does not fit back into a u64.
 ///
 /// ```rust,ignore
 /// // Constant-product swap: dy = (y * dx) / (x + dx)
@@ -98,18 +159,28 @@ pub fn checked_mul_div_ceil(a: u64, b: u64, c: u64) -> Result<u64, ProgramError>
     to_u64(result)
 }
 
+/// Basis points in 100%. Anything above this is rejected by [`bps_of`]
+/// and [`bps_of_ceil`].
+pub const MAX_BPS: u16 = 10_000;
+
 /// Compute basis-point fee: `amount * bps / 10_000` (floor).
 ///
 /// Uses u128 intermediate to prevent overflow. Nearly every DeFi program
 /// computes fees in basis points. This one-liner eliminates a whole class
 /// of bugs.
 ///
+/// Returns `InvalidArgument` if `basis_points > 10_000`: a fee above 100%
+/// is a configuration error, not an arithmetic one.
+///
 /// ```rust,ignore
 /// let fee = bps_of(trade_amount, 30)?; // 0.3% fee
 /// ```
 #[inline(always)]
 pub fn bps_of(amount: u64, basis_points: u16) -> Result<u64, ProgramError> {
-    checked_mul_div(amount, basis_points as u64, 10_000)
+    if basis_points > MAX_BPS {
+        return Err(ProgramError::InvalidArgument);
+    }
+    checked_mul_div(amount, basis_points as u64, MAX_BPS as u64)
 }
 
 /// Compute basis-point fee with ceiling: `ceil(amount * bps / 10_000)`.
@@ -117,12 +188,17 @@ pub fn bps_of(amount: u64, basis_points: u16) -> Result<u64, ProgramError> {
 /// Fees must never round to zero. Use this to ensure the protocol always
 /// collects at least 1 token unit of fee when a fee is configured.
 ///
+/// Returns `InvalidArgument` if `basis_points > 10_000`.
+///
 /// ```rust,ignore
 /// let fee = bps_of_ceil(trade_amount, 30)?; // 0.3% fee, always >= 1
 /// ```
 #[inline(always)]
 pub fn bps_of_ceil(amount: u64, basis_points: u16) -> Result<u64, ProgramError> {
-    checked_mul_div_ceil(amount, basis_points as u64, 10_000)
+    if basis_points > MAX_BPS {
+        return Err(ProgramError::InvalidArgument);
+    }
+    checked_mul_div_ceil(amount, basis_points as u64, MAX_BPS as u64)
 }
 
 /// Checked exponentiation via repeated squaring.
@@ -153,6 +229,46 @@ pub fn checked_pow(base: u64, exp: u32) -> Result<u64, ProgramError> {
     Ok(result)
 }
 
+/// Powers of ten that fit in a u64: `10^0 ..= 10^19`.
+const POW10: [u64; 20] = [
+    1,
+    10,
+    100,
+    1_000,
+    10_000,
+    100_000,
+    1_000_000,
+    10_000_000,
+    100_000_000,
+    1_000_000_000,
+    10_000_000_000,
+    100_000_000_000,
+    1_000_000_000_000,
+    10_000_000_000_000,
+    100_000_000_000_000,
+    1_000_000_000_000_000,
+    10_000_000_000_000_000,
+    100_000_000_000_000_000,
+    1_000_000_000_000_000_000,
+    10_000_000_000_000_000_000,
+];
+
+/// `10^decimals` from a lookup table.
+///
+/// Cheaper than `checked_pow(10, n)` for the decimal-scaling case.
+/// Returns `ArithmeticOverflow` for `decimals > 19`.
+///
+/// ```rust,ignore
+/// let one_token = ten_pow(mint_decimals(mint)?)?;
+/// ```
+#[inline(always)]
+pub fn ten_pow(decimals: u8) -> Result<u64, ProgramError> {
+    POW10
+        .get(decimals as usize)
+        .copied()
+        .ok_or(ProgramError::ArithmeticOverflow)
+}
+
 /// Safe narrowing cast from u128 to u64.
 ///
 /// Returns `ArithmeticOverflow` if the value exceeds `u64::MAX`.
@@ -170,6 +286,71 @@ pub fn to_u64(val: u128) -> Result<u64, ProgramError> {
     Ok(val as u64)
 }
 
+/// Safe u64 -> i64 cast. Returns `ArithmeticOverflow` if `val > i64::MAX`.
+///
+/// ```rust,ignore
+/// let delta = checked_sub_i64(u64_to_i64(balance_after)?, u64_to_i64(balance_before)?)?;
+/// ```
+#[inline(always)]
+pub fn u64_to_i64(val: u64) -> Result<i64, ProgramError> {
+    i64::try_from(val).map_err(|_| ProgramError::ArithmeticOverflow)
+}
+
+/// Safe i64 -> u64 cast. Returns `ArithmeticOverflow` if `val` is negative.
+///
+/// ```rust,ignore
+/// let elapsed = i64_to_u64(now - last_update)?;
+/// ```
+#[inline(always)]
+pub fn i64_to_u64(val: i64) -> Result<u64, ProgramError> {
+    u64::try_from(val).map_err(|_| ProgramError::ArithmeticOverflow)
+}
+
+/// Integer part of a Q64.64 fixed-point value (floor).
+///
+/// Q64.64 keeps 64 integer bits above 64 fractional bits in a `u128`,
+/// the format concentrated-liquidity AMMs use for prices.
+///
+/// ```rust,ignore
+/// let whole = q64_to_int(sqrt_price_x64);
+/// ```
+#[inline(always)]
+pub fn q64_to_int(value: u128) -> u64 {
+    (value >> 64) as u64
+}
+
+/// Convert an integer to Q64.64 fixed-point. Lossless.
+#[inline(always)]
+pub fn int_to_q64(value: u64) -> u128 {
+    (value as u128) << 64
+}
+
+/// Multiply two Q64.64 values: `(a * b) >> 64`, truncating.
+///
+/// The full product needs 256 bits, so the operands are split into
+/// 64-bit halves and the partial products summed with overflow checks.
+/// Returns `ArithmeticOverflow` if the result does not fit in Q64.64.
+///
+/// ```rust,ignore
+/// let price_x64 = q64_mul(sqrt_price_x64, sqrt_price_x64)?;
+/// ```
+#[inline(always)]
+pub fn q64_mul(a: u128, b: u128) -> Result<u128, ProgramError> {
+    let (a_hi, a_lo) = (a >> 64, a & u64::MAX as u128);
+    let (b_hi, b_lo) = (b >> 64, b & u64::MAX as u128);
+
+    // a * b = hi_hi << 128 + (hi_lo + lo_hi) << 64 + lo_lo
+    let hi_hi = a_hi * b_hi;
+    if hi_hi > u64::MAX as u128 {
+        return Err(ProgramError::ArithmeticOverflow);
+    }
+    (hi_hi << 64)
+        .checked_add(a_hi * b_lo)
+        .and_then(|n| n.checked_add(a_lo * b_hi))
+        .and_then(|n| n.checked_add((a_lo * b_lo) >> 64))
+        .ok_or(ProgramError::ArithmeticOverflow)
+}
+
 /// Scale a token amount between different decimal precisions.
 ///
 /// Converts `amount` denominated in `from_decimals` to the equivalent
@@ -204,9 +385,7 @@ pub fn scale_amount(amount: u64, from_decimals: u8, to_decimals: u8) -> Result<u
         to_u64(result)
     } else {
         // Scale down: amount / 10^(from - to) (truncates)
-        let factor = 10u64.checked_pow((from_decimals - to_decimals) as u32)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-        checked_div(amount, factor)
+        checked_div(amount, ten_pow(from_decimals - to_decimals)?)
     }
 }
 
@@ -236,8 +415,6 @@ pub fn scale_amount_ceil(amount: u64, from_decimals: u8, to_decimals: u8) -> Res
         to_u64(result)
     } else {
         // Scale down with ceiling
-        let factor = 10u64.checked_pow((from_decimals - to_decimals) as u32)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-        checked_div_ceil(amount, factor)
+        checked_div_ceil(amount, ten_pow(from_decimals - to_decimals)?)
     }
 }
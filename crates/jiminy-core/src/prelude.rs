@@ -6,32 +6,50 @@
 
 // ── Check functions ──────────────────────────────────────────────────────────
 pub use crate::check::{
-    check_account, check_accounts_unique_2, check_accounts_unique_3, check_accounts_unique_4,
-    check_closed, check_discriminator, check_executable, check_has_one,
-    check_instruction_data_len, check_instruction_data_min, check_keys_eq,
+    check_account, check_account_fast, check_account_payload_len, check_account_range,
+    check_accounts_unique_2,
+    check_accounts_unique_3, check_accounts_unique_4, check_all_distinct,
+    check_closed, check_discriminator, check_discriminator_one_of, check_each_signer,
+    check_each_writable,
+    check_exactly_one_writable, check_executable,
+    check_has_one,
+    check_instruction_data_len, check_instruction_data_max, check_instruction_data_min,
+    check_keys_eq,
     check_lamports_gte, check_owner, check_pda, check_program_allowed, check_rent_exempt,
-    check_signer, check_size, check_system_program, check_uninitialized, check_version,
-    check_writable, rent_exempt_min,
+    check_same_owner,
+    check_signer, check_size, check_size_range, check_system_program, check_uninitialized,
+    check_version,
+    check_writable, check_writable_signer, rent_exempt_min,
 };
 
+// ── Errors ───────────────────────────────────────────────────────────────────
+pub use crate::error::JiminyError;
+
 // ── Assert functions ─────────────────────────────────────────────────────────
 pub use crate::check::{
-    assert_address, assert_not_initialized, assert_pda, assert_pda_external,
-    assert_pda_with_bump, assert_program,
+    assert_address, assert_not_initialized, assert_pda, assert_pda_canonical, assert_pda_external,
+    assert_pda_with_bump, assert_pda_with_bump_checked, assert_program, verify_pdas_with_bumps,
 };
 #[cfg(feature = "programs")]
-pub use crate::check::assert_token_program;
+pub use crate::check::{assert_program_account, assert_token_program};
 
 // ── Account header ───────────────────────────────────────────────────────────
 pub use crate::account::{
     AccountHeader, body, body_mut, check_header, check_layout_id, header_payload,
-    header_payload_mut, read_header_flags, read_layout_id, read_version, write_header,
-    HEADER_LEN,
+    header_payload_mut, read_discriminator, read_header_flags, read_layout_id, read_version,
+    write_header, clear_header_flag, migrate_header, set_header_flag, toggle_header_flag,
+    update_header_flags, HEADER_LEN,
+    AccountType, check_header_for, write_header_for,
+    update_header_checksum, verify_header_checksum, write_header_checksummed,
+    check_header8, disc8_of, header8_payload, header8_payload_mut, write_header8, HEADER8_LEN,
+    HeaderMut, HeaderRef,
 };
 
 // ── Zero-copy IO ─────────────────────────────────────────────────────────────
 pub use crate::account::{AccountReader, AccountWriter};
-pub use crate::account::{write_discriminator, zero_init, DataWriter, SliceCursor};
+pub use crate::account::{
+    read_bumps, write_discriminator, zero_init, DataWriter, PayloadRef, SliceCursor,
+};
 pub use crate::account::{pod_from_bytes, pod_from_bytes_mut, pod_read, pod_write, FixedLayout, Pod};
 pub use crate::account::{ZeroCopySlice, ZeroCopySliceMut};
 pub use crate::account::{VerifiedAccount, VerifiedAccountMut};
@@ -50,29 +68,44 @@ pub use crate::account::view::validate_version_compatible;
 
 // ── Math ─────────────────────────────────────────────────────────────────────
 pub use crate::math::{
-    bps_of, bps_of_ceil, checked_add, checked_div, checked_div_ceil, checked_mul,
-    checked_mul_div, checked_mul_div_ceil, checked_pow, checked_sub, scale_amount,
-    scale_amount_ceil, to_u64,
+    bps_of, bps_of_ceil, checked_add, checked_add_i64, checked_div, checked_div_ceil,
+    checked_mul, checked_mul_i64, checked_mul_div, checked_mul_div_ceil, checked_pow,
+    checked_rem, checked_sub, checked_sub_i64,
+    i64_to_u64, int_to_q64, q64_mul, q64_to_int, saturating_add, saturating_mul, saturating_sub,
+    scale_amount, scale_amount_ceil, ten_pow, to_u64, u64_to_i64, MAX_BPS,
 };
 
 // ── Bit helpers ──────────────────────────────────────────────────────────────
 pub use crate::account::{
     check_any_flag, check_flags, clear_bit, read_bit, read_flags_at, set_bit, toggle_bit,
     write_flags_at,
+    clear_bit_at, set_bit_at, toggle_bit_at,
+    read_bit_field, write_bit_field,
+    check_any_flag_u16, check_any_flag_u32, check_any_flag_u64, check_flags_u16,
+    check_flags_u32, check_flags_u64, clear_bit16, clear_bit32, clear_bit64, read_bit16,
+    read_bit32, read_bit64, set_bit16, set_bit32, set_bit64, toggle_bit16, toggle_bit32,
+    toggle_bit64,
+    count_set_bits, count_set_bits16, count_set_bits32, count_set_bits64, first_clear_bit,
+    first_clear_bit16, first_clear_bit32, first_clear_bit64,
 };
 
 // ── Account lifecycle ────────────────────────────────────────────────────────
 pub use crate::account::{
-    safe_close, safe_close_with_sentinel, safe_realloc, safe_realloc_shrink,
-    check_not_revived, check_alive, CLOSE_SENTINEL,
+    safe_close, safe_close_reassign, safe_close_with_sentinel, realloc, safe_realloc,
+    safe_realloc_shrink, check_not_revived, check_alive, check_realloc, CLOSE_SENTINEL,
+    MAX_PERMITTED_DATA_INCREASE,
 };
 
 // ── PDA utilities ────────────────────────────────────────────────────────────
-pub use crate::check::pda::{derive_address, derive_address_const};
+pub use crate::check::pda::{
+    append_bump, derive_address, derive_address_const, find_program_address,
+};
+pub use crate::signer::PdaSigner;
 #[cfg(feature = "programs")]
 pub use crate::check::pda::{
-    check_ata, check_ata_with_program, derive_ata, derive_ata_with_bump,
+    check_ata, check_ata_with_program, check_metadata_pda, derive_ata, derive_ata_with_bump,
     derive_ata_with_program,
+    derive_metadata_pda, derive_metadata_pda_with_bump,
 };
 
 // ── Account iteration ────────────────────────────────────────────────────────
@@ -84,7 +117,7 @@ pub use crate::field::Field;
 // ── State hygiene ────────────────────────────────────────────────────────────
 pub use crate::state::{
     zero_bytes, write_version, write_disc, write_layout_id,
-    ExtensionRegion,
+    check_unchanged, data_fingerprint, ExtensionRegion,
 };
 pub use crate::packed::ReservedBytes;
 
@@ -106,7 +139,7 @@ pub use crate::sysvar::{
 
 // ── Instruction access ───────────────────────────────────────────────────────
 pub use crate::instruction::{
-    current_index, instruction_count, program_id_at, instruction_data_range,
+    current_index, instruction_count, program_id_at, sibling_program_id, instruction_data_range,
     instruction_account_key, caller_program, require_top_level, require_cpi_from,
     count_program_invocations, detect_flash_loan_bracket,
     check_no_other_invocation, check_no_subsequent_invocation,
@@ -120,7 +153,7 @@ pub use crate::event::emit_slices;
 // ── Time / deadline checks ───────────────────────────────────────────────────
 pub use crate::time::{
     check_cooldown, check_expired, check_not_expired, check_slot_staleness,
-    check_within_window,
+    check_within_window, RateLimiter, RATE_LIMITER_LEN,
 };
 #[cfg(feature = "programs")]
 pub use crate::time::{check_after, check_deadline};
@@ -132,10 +165,14 @@ pub use crate::state::{
 
 // ── Macros ───────────────────────────────────────────────────────────────────
 pub use crate::{
-    assert_legacy_layout, check_accounts_unique, close_account, error_codes, init_account,
-    instruction_dispatch, impl_pod, require, require_accounts_ne, require_eq, require_flag,
-    require_gt, require_gte, require_keys_eq, require_keys_neq, require_lt, require_lte,
-    require_neq, zero_copy_layout,
+    assert_legacy_layout, check_accounts_unique, checked_require_sub, close_account, disc_of,
+    dispatch,
+    error_codes, flags, init_account, instruction_dispatch, impl_pod, require,
+    require_account_key_eq, require_account_key_neq, require_accounts_ne, require_eq,
+    require_flag, require_gt, require_gte, require_keys_eq, require_keys_in, require_keys_neq,
+    require_lamports_gte, require_lt, require_lte, require_msg, require_neq, require_owner,
+    require_rent_exempt, require_signer, require_some, require_writable, static_assert,
+    unique_discriminators, zero_copy_layout,
     segmented_layout,
     // check_account is both a macro (check_account!) and a function (check::check_account).
     // The function is exported above via check::*. The macro is #[macro_export] at crate root.
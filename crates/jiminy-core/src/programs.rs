@@ -3,7 +3,7 @@ use hopper_runtime::Address;
 /// The system program. Lamports come from here, and rent goes here.
 ///
 /// `11111111111111111111111111111111`
-pub const SYSTEM: Address = Address::new_from_array([0u8; 32]);
+pub const SYSTEM: Address = crate::check::SYSTEM_PROGRAM_ID;
 
 /// SPL Token (original) program.
 ///
@@ -59,6 +59,31 @@ pub const BPF_LOADER: Address = Address::new_from_array([
     0, 194, 185, 61, 22, 193, 36, 210, 192, 83, 122, 16, 4, 128, 0, 0,
 ]);
 
+/// Original BPF Loader (deprecated). Owns a few early, immutable programs.
+///
+/// `BPFLoader1111111111111111111111111111111111`
+pub const BPF_LOADER_DEPRECATED: Address = Address::new_from_array([
+    2, 168, 246, 145, 78, 136, 161, 107, 189, 35, 149, 133, 95, 100, 4, 217,
+    180, 244, 86, 183, 130, 27, 176, 20, 87, 73, 66, 140, 0, 0, 0, 0,
+]);
+
+/// BPF Loader 2 (non-upgradeable). Owns finalized programs such as SPL
+/// Token.
+///
+/// `BPFLoader2111111111111111111111111111111111`
+pub const BPF_LOADER_2: Address = Address::new_from_array([
+    2, 168, 246, 145, 78, 136, 161, 110, 57, 90, 225, 40, 148, 143, 250, 105,
+    86, 147, 55, 104, 24, 221, 71, 67, 82, 33, 243, 198, 0, 0, 0, 0,
+]);
+
+/// Loader v4.
+///
+/// `LoaderV411111111111111111111111111111111111`
+pub const LOADER_V4: Address = Address::new_from_array([
+    5, 18, 180, 17, 81, 81, 227, 122, 173, 10, 139, 197, 211, 136, 46, 123,
+    127, 218, 76, 243, 210, 192, 40, 200, 207, 131, 54, 24, 0, 0, 0, 0,
+]);
+
 /// Compute Budget program.
 ///
 /// Used to set `ComputeUnitLimit` and `ComputeUnitPrice` via instructions
@@ -71,6 +96,56 @@ pub const COMPUTE_BUDGET: Address = Address::new_from_array([
     188, 140, 229, 187, 197, 247, 18, 107, 44, 67, 155, 58, 64, 0, 0, 0,
 ]);
 
+/// Native Stake program.
+///
+/// Owns stake accounts. Check against this before reading delegation
+/// state from an account passed in by a staking integration.
+///
+/// `Stake11111111111111111111111111111111111111`
+pub const STAKE: Address = Address::new_from_array([
+    6, 161, 216, 23, 145, 55, 84, 42, 152, 52, 55, 189, 254, 42, 122, 178,
+    85, 127, 83, 92, 138, 120, 114, 43, 104, 164, 157, 192, 0, 0, 0, 0,
+]);
+
+/// Native Vote program. Owns validator vote accounts.
+///
+/// `Vote111111111111111111111111111111111111111`
+pub const VOTE: Address = Address::new_from_array([
+    7, 97, 72, 29, 53, 116, 116, 187, 124, 77, 118, 36, 235, 211, 189, 179,
+    216, 53, 94, 115, 209, 16, 67, 252, 13, 163, 83, 128, 0, 0, 0, 0,
+]);
+
+/// Address Lookup Table program.
+///
+/// Owns the lookup tables that v0 transactions use to reference more
+/// accounts than fit in the message.
+///
+/// `AddressLookupTab1e1111111111111111111111111`
+pub const ADDRESS_LOOKUP_TABLE: Address = Address::new_from_array([
+    2, 119, 166, 175, 151, 51, 155, 122, 200, 141, 24, 146, 201, 4, 70, 245,
+    0, 2, 48, 146, 102, 246, 46, 83, 193, 24, 36, 73, 130, 0, 0, 0,
+]);
+
+/// Ed25519 signature-verification precompile.
+///
+/// A transaction includes an instruction to this program to have the
+/// runtime verify signatures; a later instruction confirms it ran by
+/// reading the Instructions sysvar (see `instruction::sibling_program_id`).
+///
+/// `Ed25519SigVerify111111111111111111111111111`
+pub const ED25519: Address = Address::new_from_array([
+    3, 125, 70, 214, 124, 147, 251, 190, 18, 249, 66, 143, 131, 141, 64, 255,
+    5, 112, 116, 73, 39, 244, 138, 100, 252, 202, 112, 68, 128, 0, 0, 0,
+]);
+
+/// Secp256k1 (Ethereum-style `ecrecover`) signature-verification precompile.
+///
+/// `KeccakSecp256k11111111111111111111111111111`
+pub const SECP256K1: Address = Address::new_from_array([
+    4, 198, 252, 32, 240, 80, 204, 240, 85, 132, 215, 33, 28, 159, 140, 245,
+    158, 193, 71, 133, 187, 22, 106, 30, 40, 48, 232, 18, 32, 0, 0, 0,
+]);
+
 /// Sysvar: Clock (slot, epoch, unix_timestamp, leader_schedule_epoch).
 ///
 /// `SysvarC1ock11111111111111111111111111111111`
@@ -94,3 +169,100 @@ pub const SYSVAR_INSTRUCTIONS: Address = Address::new_from_array([
     6, 167, 213, 23, 24, 123, 209, 102, 53, 218, 212, 4, 85, 253, 194, 192,
     193, 36, 198, 143, 33, 86, 117, 165, 219, 186, 203, 95, 8, 0, 0, 0,
 ]);
+
+/// Sysvar: SlotHashes (most recent slot hashes, newest first).
+///
+/// `SysvarS1otHashes111111111111111111111111111`
+pub const SYSVAR_SLOT_HASHES: Address = Address::new_from_array([
+    6, 167, 213, 23, 25, 47, 10, 175, 198, 242, 101, 227, 251, 119, 204, 122,
+    218, 130, 197, 41, 208, 190, 59, 19, 110, 45, 0, 85, 32, 0, 0, 0,
+]);
+
+/// Sysvar: EpochSchedule (slots per epoch, warmup parameters).
+///
+/// `SysvarEpochSchedu1e111111111111111111111111`
+pub const SYSVAR_EPOCH_SCHEDULE: Address = Address::new_from_array([
+    6, 167, 213, 23, 24, 220, 63, 238, 2, 211, 228, 127, 1, 0, 248, 176,
+    84, 247, 148, 46, 96, 89, 30, 63, 80, 135, 25, 168, 5, 0, 0, 0,
+]);
+
+// ── Lookup ───────────────────────────────────────────────────────────────────
+
+/// Sysvars recognized by [`is_sysvar`].
+const SYSVARS: [Address; 5] = [
+    SYSVAR_CLOCK,
+    SYSVAR_RENT,
+    SYSVAR_INSTRUCTIONS,
+    SYSVAR_SLOT_HASHES,
+    SYSVAR_EPOCH_SCHEDULE,
+];
+
+/// `true` if `address` is one of the sysvars listed in this module.
+///
+/// Use it to reject a sysvar passed where a data account is expected:
+///
+/// ```rust,ignore
+/// require!(!is_sysvar(vault.address()), ProgramError::InvalidArgument);
+/// ```
+#[inline(always)]
+pub fn is_sysvar(address: &Address) -> bool {
+    SYSVARS.contains(address)
+}
+
+/// Program loaders recognized by [`is_loader`].
+const LOADERS: [Address; 4] = [BPF_LOADER, BPF_LOADER_2, BPF_LOADER_DEPRECATED, LOADER_V4];
+
+/// `true` if `address` is one of the program loaders listed in this module.
+///
+/// Deployed programs are owned by a loader. For an account, prefer
+/// [`assert_program_account`](crate::check::assert_program_account),
+/// which also checks the executable flag.
+#[inline(always)]
+pub fn is_loader(address: &Address) -> bool {
+    LOADERS.contains(address)
+}
+
+/// `true` if `address` is SPL Token or Token-2022.
+///
+/// For an account passed as the token program, prefer
+/// [`assert_token_program`](crate::check::assert_token_program).
+#[inline(always)]
+pub fn is_token_program(address: &Address) -> bool {
+    *address == TOKEN || *address == TOKEN_2022
+}
+
+/// Human-readable name for an address in this module, for logging.
+///
+/// Returns `None` for addresses not listed here.
+pub fn program_name(address: &Address) -> Option<&'static str> {
+    const NAMES: [(Address, &str); 20] = [
+        (SYSTEM, "System Program"),
+        (TOKEN, "SPL Token"),
+        (TOKEN_2022, "SPL Token-2022"),
+        (ASSOCIATED_TOKEN, "Associated Token Account"),
+        (METADATA, "Token Metadata"),
+        (BPF_LOADER, "BPF Loader Upgradeable"),
+        (BPF_LOADER_2, "BPF Loader 2"),
+        (BPF_LOADER_DEPRECATED, "BPF Loader (deprecated)"),
+        (LOADER_V4, "Loader v4"),
+        (COMPUTE_BUDGET, "Compute Budget"),
+        (STAKE, "Stake Program"),
+        (VOTE, "Vote Program"),
+        (ADDRESS_LOOKUP_TABLE, "Address Lookup Table"),
+        (ED25519, "Ed25519 Precompile"),
+        (SECP256K1, "Secp256k1 Precompile"),
+        (SYSVAR_CLOCK, "Sysvar: Clock"),
+        (SYSVAR_RENT, "Sysvar: Rent"),
+        (SYSVAR_INSTRUCTIONS, "Sysvar: Instructions"),
+        (SYSVAR_SLOT_HASHES, "Sysvar: SlotHashes"),
+        (SYSVAR_EPOCH_SCHEDULE, "Sysvar: EpochSchedule"),
+    ];
+    let mut i = 0;
+    while i < NAMES.len() {
+        if NAMES[i].0 == *address {
+            return Some(NAMES[i].1);
+        }
+        i += 1;
+    }
+    None
+}
@@ -0,0 +1,81 @@
+//! Owned PDA signer seeds.
+//!
+//! [`PdaSigner`] keeps the seed slices and the bump byte together in one
+//! value, so the bump cannot be a temporary that is dropped before
+//! `invoke_signed` reads it. The `seeds ++ [bump]` view is only handed
+//! out borrowed from the signer, which the borrow checker then keeps
+//! alive for the whole CPI.
+//!
+//! ```rust,ignore
+//! let vault_signer = PdaSigner::new([b"vault", authority.as_ref()], vault_bump);
+//!
+//! vault_signer.with_signer(|signer| {
+//!     Transfer { from: vault, to: user, lamports }.invoke_signed(&[signer])
+//! })?;
+//! ```
+//!
+//! For a signer bound once in the handler scope, [`signer_seeds!`](crate::signer_seeds)
+//! does the same job without a closure.
+
+use hopper_runtime::address::MAX_SEEDS;
+use hopper_runtime::cpi::{Seed, Signer};
+
+/// PDA seeds plus the bump byte they sign with.
+///
+/// `N` is the number of seeds excluding the bump; `N + 1` must not
+/// exceed [`MAX_SEEDS`], checked at compile time.
+pub struct PdaSigner<'a, const N: usize> {
+    seeds: [&'a [u8]; N],
+    bump: [u8; 1],
+}
+
+impl<'a, const N: usize> PdaSigner<'a, N> {
+    /// Bundle `seeds` with `bump`.
+    #[inline(always)]
+    pub const fn new(seeds: [&'a [u8]; N], bump: u8) -> Self {
+        const {
+            assert!(N < MAX_SEEDS, "number of seeds must be less than MAX_SEEDS");
+        }
+        Self { seeds, bump: [bump] }
+    }
+
+    /// The bump byte.
+    #[inline(always)]
+    pub const fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    /// Fill `buf` with `seeds ++ [bump]` and return the filled prefix.
+    ///
+    /// The bump slice points into `self`. Use this where a plain seed
+    /// list is needed, e.g. `create_program_address`.
+    #[inline(always)]
+    pub fn seeds<'s, 'b>(&'s self, buf: &'b mut [&'s [u8]; MAX_SEEDS]) -> &'b [&'s [u8]] {
+        let mut i = 0;
+        while i < N {
+            buf[i] = self.seeds[i];
+            i += 1;
+        }
+        buf[N] = &self.bump;
+        &buf[..N + 1]
+    }
+
+    /// Run `f` with a [`Signer`] for `seeds ++ [bump]`.
+    ///
+    /// The signer only lives for the call, so it cannot outlive the seed
+    /// storage it points at.
+    #[inline(always)]
+    pub fn with_signer<R>(&self, f: impl FnOnce(Signer<'_, '_>) -> R) -> R {
+        let seeds: [Seed<'_>; MAX_SEEDS] = core::array::from_fn(|i| {
+            let seed: &[u8] = if i < N {
+                self.seeds[i]
+            } else if i == N {
+                &self.bump
+            } else {
+                &[]
+            };
+            Seed::from(seed)
+        });
+        f(Signer::from(&seeds[..N + 1]))
+    }
+}
@@ -166,6 +166,47 @@ pub fn write_layout_id(data: &mut [u8], layout_id: &[u8; 8]) -> ProgramResult {
     Ok(())
 }
 
+// ══════════════════════════════════════════════════════════════════════
+//  Tamper checks
+// ══════════════════════════════════════════════════════════════════════
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Cheap 64-bit fingerprint (FNV-1a) of a data region.
+///
+/// Not cryptographic: it detects accidental or mid-instruction mutation,
+/// not a chosen collision. Use with [`check_unchanged`] to verify a region
+/// survived a CPI untouched.
+#[inline(always)]
+pub const fn data_fingerprint(data: &[u8]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS;
+    let mut i = 0;
+    while i < data.len() {
+        hash ^= data[i] as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+        i += 1;
+    }
+    hash
+}
+
+/// Verify `data` still matches a fingerprint taken earlier.
+///
+/// Returns `InvalidAccountData` if the region changed.
+///
+/// ```rust,ignore
+/// let before = data_fingerprint(&config.try_borrow()?);
+/// invoke_signed(&ix, &accounts, &signers)?;
+/// check_unchanged(&config.try_borrow()?, before)?;
+/// ```
+#[inline(always)]
+pub fn check_unchanged(data: &[u8], fingerprint: u64) -> ProgramResult {
+    if data_fingerprint(data) != fingerprint {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(())
+}
+
 // ══════════════════════════════════════════════════════════════════════
 //  Extension region
 // ══════════════════════════════════════════════════════════════════════
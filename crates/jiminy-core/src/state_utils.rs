@@ -1,3 +1,5 @@
 //! State hygiene helpers for zero-copy lifecycle management.
 
-pub use crate::state::{write_disc, write_layout_id, write_version, zero_bytes};
+pub use crate::state::{
+    check_unchanged, data_fingerprint, write_disc, write_layout_id, write_version, zero_bytes,
+};
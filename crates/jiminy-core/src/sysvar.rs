@@ -50,6 +50,7 @@ extern "C" {
 /// let now = clock_timestamp()?;
 /// check_not_expired(now, order.expiry)?;
 /// ```
+#[doc(alias = "unix_timestamp")]
 #[inline(always)]
 pub fn clock_timestamp() -> Result<i64, ProgramError> {
     let buf = get_clock_buf()?;
@@ -232,6 +233,7 @@ pub fn read_clock_slot(account: &AccountView) -> Result<u64, ProgramError> {
 /// check_not_expired(now, listing.expiry)?;
 /// ```
 #[cfg(feature = "programs")]
+#[doc(alias = "unix_timestamp")]
 #[inline(always)]
 pub fn read_clock_timestamp(account: &AccountView) -> Result<i64, ProgramError> {
     check_clock_sysvar(account)?;
@@ -150,3 +150,70 @@ pub fn check_slot_staleness(
     }
     Ok(())
 }
+
+// ── Rate limiting ────────────────────────────────────────────────────────────
+
+/// Bytes a [`RateLimiter`] window occupies in account data:
+/// `window_start_slot` (u64 LE) followed by `count` (u64 LE).
+pub const RATE_LIMITER_LEN: usize = 16;
+
+/// Fixed-window action limit stored in account data.
+///
+/// Allows at most `max_per_window` actions per `window_slots` slots.
+/// The window state lives in [`RATE_LIMITER_LEN`] bytes of the caller's
+/// account; the limits themselves are program constants.
+///
+/// ```rust,ignore
+/// // ~1 day at 400ms slots, 3 claims per day.
+/// const CLAIMS: RateLimiter = RateLimiter::new(216_000, 3);
+///
+/// let slot = read_clock_slot(clock)?;
+/// let mut data = user.try_borrow_mut()?;
+/// CLAIMS.try_consume(&mut data, LIMITER_OFFSET, slot)?;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateLimiter {
+    /// Window length in slots.
+    pub window_slots: u64,
+    /// Actions allowed per window.
+    pub max_per_window: u64,
+}
+
+impl RateLimiter {
+    #[inline(always)]
+    pub const fn new(window_slots: u64, max_per_window: u64) -> Self {
+        Self { window_slots, max_per_window }
+    }
+
+    /// Record one action at `current_slot`.
+    ///
+    /// Starts a fresh window if the stored one has elapsed, then fails
+    /// with `InvalidArgument` if the window is already at its cap.
+    /// Nothing is written on failure. Returns `AccountDataTooSmall` if
+    /// the window state does not fit at `offset`.
+    #[inline(always)]
+    pub fn try_consume(&self, data: &mut [u8], offset: usize, current_slot: u64) -> ProgramResult {
+        let end = offset
+            .checked_add(RATE_LIMITER_LEN)
+            .ok_or(ProgramError::AccountDataTooSmall)?;
+        if end > data.len() {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+        let state = &mut data[offset..end];
+
+        let mut window_start = u64::from_le_bytes(state[0..8].try_into().unwrap());
+        let mut count = u64::from_le_bytes(state[8..16].try_into().unwrap());
+
+        if current_slot >= window_start.saturating_add(self.window_slots) {
+            window_start = current_slot;
+            count = 0;
+        }
+        if count >= self.max_per_window {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        state[0..8].copy_from_slice(&window_start.to_le_bytes());
+        state[8..16].copy_from_slice(&(count + 1).to_le_bytes());
+        Ok(())
+    }
+}
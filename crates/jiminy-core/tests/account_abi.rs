@@ -138,6 +138,14 @@ fn check_header_rejects_too_small() {
     assert!(check_header(&buf, 0, 0, &[0; 8]).is_err());
 }
 
+#[test]
+fn read_discriminator_works() {
+    let mut buf = AlignedBuf::<64>::new();
+    stamp_header(buf.as_mut_slice(), 9, 1, &[0; 8]);
+    assert_eq!(read_discriminator(buf.as_slice()).unwrap(), 9);
+    assert!(read_discriminator(&[]).is_err());
+}
+
 #[test]
 fn read_version_works() {
     let mut buf = AlignedBuf::<64>::new();
@@ -414,6 +422,159 @@ fn read_header_flags_works() {
     assert_eq!(read_header_flags(buf.as_slice()).unwrap(), 0x1234);
 }
 
+#[test]
+fn header_flag_helpers_mutate_in_place() {
+    let mut buf = AlignedBuf::<64>::new();
+    stamp_header(buf.as_mut_slice(), 1, 1, &[0; 8]);
+    set_header_flag(buf.as_mut_slice(), 0).unwrap();
+    set_header_flag(buf.as_mut_slice(), 15).unwrap();
+    assert_eq!(read_header_flags(buf.as_slice()).unwrap(), 0x8001);
+    clear_header_flag(buf.as_mut_slice(), 0).unwrap();
+    toggle_header_flag(buf.as_mut_slice(), 9).unwrap();
+    assert_eq!(read_header_flags(buf.as_slice()).unwrap(), 0x8200);
+    toggle_header_flag(buf.as_mut_slice(), 15).unwrap();
+    assert_eq!(read_header_flags(buf.as_slice()).unwrap(), 0x0200);
+    // Neighbouring header fields are untouched.
+    assert_eq!(read_version(buf.as_slice()).unwrap(), 1);
+    assert_eq!(read_layout_id(buf.as_slice()).unwrap(), [0; 8]);
+}
+
+#[test]
+fn update_header_flags_clears_then_sets() {
+    const PENDING: u16 = 1 << 0;
+    const ACTIVE: u16 = 1 << 1;
+    let mut buf = AlignedBuf::<64>::new();
+    stamp_header(buf.as_mut_slice(), 1, 1, &[0; 8]);
+    update_header_flags(buf.as_mut_slice(), 0, PENDING | 0x8000).unwrap();
+    update_header_flags(buf.as_mut_slice(), PENDING, ACTIVE).unwrap();
+    assert_eq!(read_header_flags(buf.as_slice()).unwrap(), 0x8000 | ACTIVE);
+    // A bit in both masks ends up set.
+    update_header_flags(buf.as_mut_slice(), ACTIVE, ACTIVE).unwrap();
+    assert_eq!(read_header_flags(buf.as_slice()).unwrap(), 0x8000 | ACTIVE);
+    assert_eq!(read_version(buf.as_slice()).unwrap(), 1);
+    let mut short = [0u8; 3];
+    assert!(update_header_flags(&mut short, 0, 1).is_err());
+}
+
+#[test]
+fn header_flag_helpers_reject_bad_input() {
+    let mut buf = AlignedBuf::<64>::new();
+    assert!(set_header_flag(buf.as_mut_slice(), 16).is_err());
+    assert!(clear_header_flag(buf.as_mut_slice(), 16).is_err());
+    assert!(toggle_header_flag(buf.as_mut_slice(), 16).is_err());
+    let mut short = [0u8; 3];
+    assert!(set_header_flag(&mut short, 0).is_err());
+    assert!(toggle_header_flag(&mut short, 0).is_err());
+}
+
+#[test]
+fn migrate_header_bumps_version_and_layout_id() {
+    let mut buf = AlignedBuf::<64>::new();
+    stamp_header(buf.as_mut_slice(), 4, 1, &[1; 8]);
+    set_header_flag(buf.as_mut_slice(), 3).unwrap();
+    migrate_header(buf.as_mut_slice(), 4, 1, 2, &[2; 8]).unwrap();
+    assert_eq!(read_discriminator(buf.as_slice()).unwrap(), 4);
+    assert_eq!(read_version(buf.as_slice()).unwrap(), 2);
+    assert_eq!(read_layout_id(buf.as_slice()).unwrap(), [2; 8]);
+    assert_eq!(read_header_flags(buf.as_slice()).unwrap(), 1 << 3);
+}
+
+#[test]
+fn migrate_header_rejects_wrong_state() {
+    let mut buf = AlignedBuf::<64>::new();
+    stamp_header(buf.as_mut_slice(), 4, 2, &[1; 8]);
+    // Wrong disc, wrong current version, non-increasing target.
+    assert!(migrate_header(buf.as_mut_slice(), 5, 2, 3, &[3; 8]).is_err());
+    assert!(migrate_header(buf.as_mut_slice(), 4, 1, 3, &[3; 8]).is_err());
+    assert!(migrate_header(buf.as_mut_slice(), 4, 2, 2, &[3; 8]).is_err());
+    assert!(migrate_header(&mut [4u8, 2, 0, 0], 4, 2, 3, &[3; 8]).is_err());
+    // Nothing was written.
+    assert_eq!(read_version(buf.as_slice()).unwrap(), 2);
+    assert_eq!(read_layout_id(buf.as_slice()).unwrap(), [1; 8]);
+}
+
+#[test]
+fn header_checksum_detects_payload_change() {
+    let mut buf = AlignedBuf::<64>::new();
+    buf.0[16..25].copy_from_slice(b"123456789");
+    write_header_checksummed(&mut buf.0[..25], 4, 1, &[1; 8]).unwrap();
+    // CRC-8/SMBUS check value for "123456789".
+    assert_eq!(buf.0[HEADER_CHECKSUM_OFFSET], 0xF4);
+    assert!(verify_header_checksum(&buf.0[..25]).is_ok());
+
+    buf.0[20] ^= 1;
+    assert!(verify_header_checksum(&buf.0[..25]).is_err());
+    update_header_checksum(&mut buf.0[..25]).unwrap();
+    assert!(verify_header_checksum(&buf.0[..25]).is_ok());
+    assert!(verify_header_checksum(&[0u8; 15]).is_err());
+}
+
+#[test]
+fn header_ref_reads_all_fields() {
+    let mut buf = AlignedBuf::<64>::new();
+    let id = [1, 2, 3, 4, 5, 6, 7, 8];
+    stamp_header(buf.as_mut_slice(), 3, 2, &id);
+    buf.0[2] = 0x05;
+    let h = HeaderRef::load(buf.as_slice()).unwrap();
+    assert_eq!(h.discriminator(), 3);
+    assert_eq!(h.version(), 2);
+    assert_eq!(h.flags(), 0x0005);
+    assert!(h.flag(0) && !h.flag(1) && h.flag(2));
+    assert!(!h.flag(16));
+    assert_eq!(h.layout_id(), id);
+    assert!(HeaderRef::load(&[0u8; 15]).is_err());
+}
+
+#[test]
+fn header_mut_writes_in_place() {
+    let mut buf = AlignedBuf::<64>::new();
+    stamp_header(buf.as_mut_slice(), 3, 1, &[0; 8]);
+    {
+        let mut h = HeaderMut::load(buf.as_mut_slice()).unwrap();
+        h.set_version(2);
+        h.set_flag(15).unwrap();
+        h.set_flag(1).unwrap();
+        h.clear_flag(1).unwrap();
+        assert!(h.set_flag(16).is_err());
+        h.set_layout_id(&[9; 8]);
+        assert_eq!(h.flags(), 0x8000);
+    }
+    assert_eq!(read_version(buf.as_slice()).unwrap(), 2);
+    assert_eq!(read_header_flags(buf.as_slice()).unwrap(), 0x8000);
+    assert_eq!(read_layout_id(buf.as_slice()).unwrap(), [9; 8]);
+    assert_eq!(read_discriminator(buf.as_slice()).unwrap(), 3);
+}
+
+#[test]
+fn header8_roundtrip() {
+    const DISC: [u8; 8] = disc8_of("account:Vault");
+    let mut buf = AlignedBuf::<64>::new();
+    write_header8(buf.as_mut_slice(), &DISC, 3, &[7; 8]).unwrap();
+    assert!(check_header8(buf.as_slice(), &DISC, 3, &[7; 8]).is_ok());
+    assert!(check_header8(buf.as_slice(), &DISC, 2, &[7; 8]).is_ok());
+    assert_eq!(header8_payload(buf.as_slice()).unwrap().len(), 64 - HEADER8_LEN);
+    header8_payload_mut(buf.as_mut_slice()).unwrap()[0] = 0xAA;
+    assert_eq!(buf.0[HEADER8_LEN], 0xAA);
+}
+
+#[test]
+fn header8_rejects_mismatch() {
+    let disc = disc8_of("account:Vault");
+    let mut buf = AlignedBuf::<64>::new();
+    write_header8(buf.as_mut_slice(), &disc, 1, &[7; 8]).unwrap();
+    assert!(check_header8(buf.as_slice(), &disc8_of("account:Pool"), 1, &[7; 8]).is_err());
+    assert!(check_header8(buf.as_slice(), &disc, 2, &[7; 8]).is_err());
+    assert!(check_header8(buf.as_slice(), &disc, 1, &[8; 8]).is_err());
+    assert!(check_header8(&buf.as_slice()[..HEADER8_LEN - 1], &disc, 1, &[7; 8]).is_err());
+    assert!(header8_payload(&[0u8; 23]).is_err());
+}
+
+#[test]
+fn disc8_of_matches_anchor() {
+    // Anchor: sha256("account:Vault")[..8]
+    assert_eq!(disc8_of("account:Vault"), [211, 8, 232, 43, 2, 152, 117, 119]);
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // 10. Size assertions (compile-time: if these types exist, the assertions passed)
 // ══════════════════════════════════════════════════════════════════════════════
@@ -1435,3 +1596,15 @@ mod segmented_interface_tests {
         );
     }
 }
+
+#[test]
+fn account_type_header_roundtrip() {
+    assert_eq!(<TestVault as AccountType>::DISC, TestVault::DISC);
+    assert_eq!(<TestVault as AccountType>::LAYOUT_ID, TestVault::LAYOUT_ID);
+
+    let mut data = [0u8; TestVault::LEN];
+    write_header_for::<TestVault>(&mut data).unwrap();
+    assert!(check_header_for::<TestVault>(&data).is_ok());
+    assert!(check_header_for::<TestVaultV2>(&data).is_err());
+    assert!(check_header_for::<TestVault>(&data[..8]).is_err());
+}
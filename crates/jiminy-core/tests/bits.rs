@@ -0,0 +1,136 @@
+//! Tests for the bit and flag helpers.
+
+use jiminy_core::account::*;
+use jiminy_core::ProgramError;
+
+// ── Multi-bit fields ─────────────────────────────────────────────────────────
+
+#[test]
+fn bit_field_round_trip_keeps_other_bits() {
+    let byte = write_bit_field(0b1000_0011, 2, 3, 0b101).unwrap();
+    assert_eq!(byte, 0b1001_0111);
+    assert_eq!(read_bit_field(byte, 2, 3), Ok(0b101));
+    assert_eq!(read_bit_field(byte, 0, 8), Ok(byte));
+    assert_eq!(write_bit_field(0xFF, 5, 3, 0), Ok(0b0001_1111));
+}
+
+#[test]
+fn bit_field_rejects_bad_geometry_and_overflow() {
+    assert_eq!(read_bit_field(0, 6, 3), Err(ProgramError::InvalidArgument));
+    assert_eq!(read_bit_field(0, 0, 0), Err(ProgramError::InvalidArgument));
+    assert_eq!(read_bit_field(0, 255, 255), Err(ProgramError::InvalidArgument));
+    assert_eq!(write_bit_field(0, 2, 2, 0b100), Err(ProgramError::InvalidArgument));
+}
+
+// ── Wide bitfields ───────────────────────────────────────────────────────────
+
+#[test]
+fn wide_bits_set_read_clear_at_edges() {
+    let v = set_bit32(0, 31).unwrap();
+    assert_eq!(v, 1 << 31);
+    assert_eq!(read_bit32(v, 31), Ok(true));
+    assert_eq!(read_bit32(v, 0), Ok(false));
+    assert_eq!(clear_bit32(v, 31), Ok(0));
+
+    assert_eq!(set_bit16(0, 15), Ok(0x8000));
+    assert_eq!(read_bit64(set_bit64(0, 63).unwrap(), 63), Ok(true));
+    assert_eq!(clear_bit64(u64::MAX, 0), Ok(u64::MAX - 1));
+}
+
+#[test]
+fn wide_bits_reject_out_of_range_index() {
+    assert_eq!(read_bit16(0, 16), Err(ProgramError::InvalidArgument));
+    assert_eq!(set_bit16(0, 16), Err(ProgramError::InvalidArgument));
+    assert_eq!(clear_bit32(0, 32), Err(ProgramError::InvalidArgument));
+    assert_eq!(set_bit64(0, 64), Err(ProgramError::InvalidArgument));
+    assert_eq!(read_bit64(0, u8::MAX), Err(ProgramError::InvalidArgument));
+}
+
+#[test]
+fn wide_flag_masks() {
+    let v: u32 = 0b1010_0000_0000_0000_0000_0000_0000_0101;
+    assert!(check_flags_u32(v, 0b101));
+    assert!(!check_flags_u32(v, 0b111));
+    assert!(check_any_flag_u32(v, 0b011));
+    assert!(!check_any_flag_u32(v, 0b010));
+    assert!(check_flags_u16(0xFFFF, 0x8001));
+    assert!(check_any_flag_u64(1 << 63, u64::MAX));
+    assert!(!check_flags_u64(0, 1));
+}
+
+// ── In-place flags ───────────────────────────────────────────────────────────
+
+#[test]
+fn bit_at_mutates_only_target_bit() {
+    let mut data = [0u8, 0b1000_0000, 0];
+    set_bit_at(&mut data, 1, 0).unwrap();
+    assert_eq!(data, [0, 0b1000_0001, 0]);
+    clear_bit_at(&mut data, 1, 7).unwrap();
+    assert_eq!(data, [0, 0b0000_0001, 0]);
+    toggle_bit_at(&mut data, 2, 3).unwrap();
+    toggle_bit_at(&mut data, 1, 0).unwrap();
+    assert_eq!(data, [0, 0, 0b0000_1000]);
+}
+
+#[test]
+fn bit_at_rejects_bad_offset_or_index() {
+    let mut data = [0u8; 2];
+    assert_eq!(set_bit_at(&mut data, 2, 0), Err(ProgramError::AccountDataTooSmall));
+    assert_eq!(clear_bit_at(&mut data, 0, 8), Err(ProgramError::InvalidArgument));
+    assert_eq!(toggle_bit_at(&mut [], 0, 0), Err(ProgramError::AccountDataTooSmall));
+    assert_eq!(data, [0, 0]);
+}
+
+// ── Wide bits ────────────────────────────────────────────────────────────────
+
+#[test]
+fn toggle_wide_bits_flips_and_bounds_checks() {
+    assert_eq!(toggle_bit16(0x0001, 0), Ok(0x0000));
+    assert_eq!(toggle_bit16(0x0000, 15), Ok(0x8000));
+    assert_eq!(toggle_bit16(0, 16), Err(ProgramError::InvalidArgument));
+    assert_eq!(toggle_bit32(0, 31), Ok(1 << 31));
+    assert_eq!(toggle_bit32(0, 32), Err(ProgramError::InvalidArgument));
+    assert_eq!(toggle_bit64(u64::MAX, 63), Ok(u64::MAX >> 1));
+    assert_eq!(toggle_bit64(0, 64), Err(ProgramError::InvalidArgument));
+}
+
+// ── Bitmap scanning ──────────────────────────────────────────────────────────
+
+#[test]
+fn count_set_bits_all_widths() {
+    assert_eq!(count_set_bits(0), 0);
+    assert_eq!(count_set_bits(0b1011_0001), 4);
+    assert_eq!(count_set_bits(u8::MAX), 8);
+    assert_eq!(count_set_bits16(0x8001), 2);
+    assert_eq!(count_set_bits32(u32::MAX), 32);
+    assert_eq!(count_set_bits64(u64::MAX >> 1), 63);
+}
+
+#[test]
+fn first_clear_bit_finds_lowest_free_slot() {
+    assert_eq!(first_clear_bit(0), Some(0));
+    assert_eq!(first_clear_bit(0b0000_0111), Some(3));
+    assert_eq!(first_clear_bit(0b1111_0111), Some(3));
+    assert_eq!(first_clear_bit(0b0111_1111), Some(7));
+    assert_eq!(first_clear_bit(u8::MAX), None);
+
+    assert_eq!(first_clear_bit16(0x00FF), Some(8));
+    assert_eq!(first_clear_bit16(u16::MAX), None);
+    assert_eq!(first_clear_bit32(u32::MAX >> 1), Some(31));
+    assert_eq!(first_clear_bit64(u64::MAX), None);
+    assert_eq!(first_clear_bit64(u64::MAX - (1 << 40)), Some(40));
+}
+
+#[test]
+fn first_clear_bit_then_set_allocates_slots() {
+    let mut bitmap = 0u8;
+    let mut n = 0;
+    while let Some(slot) = first_clear_bit(bitmap) {
+        bitmap = set_bit(bitmap, slot);
+        n += 1;
+    }
+    assert_eq!(n, 8);
+    assert_eq!(count_set_bits(bitmap), 8);
+}
+
+const _: () = assert!(count_set_bits(0b1010) == 2);
@@ -0,0 +1,134 @@
+//! Tests for the data-level check helpers.
+
+use jiminy_core::account::{
+    read_bumps, realloc_top_up, MAX_PERMITTED_DATA_INCREASE, MAX_PERMITTED_DATA_LENGTH,
+};
+use jiminy_core::check::*;
+use jiminy_core::state::{check_unchanged, data_fingerprint};
+use jiminy_core::ProgramError;
+
+// ── Data shape ───────────────────────────────────────────────────────────────
+
+#[cfg(not(feature = "granular-errors"))]
+#[test]
+fn discriminator_one_of_returns_match() {
+    assert_eq!(check_discriminator_one_of(&[2, 9], &[1, 2, 3]), Ok(2));
+    assert_eq!(check_discriminator_one_of(&[4], &[1, 2, 3]), Err(ProgramError::InvalidAccountData));
+    assert_eq!(check_discriminator_one_of(&[], &[0]), Err(ProgramError::InvalidAccountData));
+    assert_eq!(check_discriminator_one_of(&[1], &[]), Err(ProgramError::InvalidAccountData));
+}
+
+#[test]
+fn size_range_accepts_bounds() {
+    assert!(check_size_range(&[0u8; 8], 8, 16).is_ok());
+    assert!(check_size_range(&[0u8; 16], 8, 16).is_ok());
+    assert_eq!(check_size_range(&[0u8; 17], 8, 16), Err(ProgramError::InvalidAccountData));
+}
+
+#[test]
+fn size_range_rejects_swapped_bounds() {
+    assert_eq!(check_size_range(&[0u8; 12], 16, 8), Err(ProgramError::InvalidArgument));
+}
+
+#[cfg(not(feature = "granular-errors"))]
+#[test]
+fn size_range_rejects_short_data() {
+    assert_eq!(check_size_range(&[0u8; 7], 8, 16), Err(ProgramError::AccountDataTooSmall));
+}
+
+// ── Instruction data ─────────────────────────────────────────────────────────
+
+#[test]
+fn instruction_data_max_accepts_at_limit() {
+    assert!(check_instruction_data_max(&[0u8; 8], 8).is_ok());
+    assert!(check_instruction_data_max(&[0u8; 3], 8).is_ok());
+    assert!(check_instruction_data_max(&[], 0).is_ok());
+}
+
+#[test]
+fn instruction_data_max_rejects_above_limit() {
+    assert_eq!(
+        check_instruction_data_max(&[0u8; 9], 8),
+        Err(ProgramError::InvalidInstructionData)
+    );
+    assert_eq!(
+        check_instruction_data_max(&[0u8; 1], 0),
+        Err(ProgramError::InvalidInstructionData)
+    );
+}
+
+// ── Realloc ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn realloc_growth_within_cap_reports_rent_deficit() {
+    let funded = rent_exempt_min(100);
+    // 0 -> 100 with only the old rent: must top up the difference.
+    assert_eq!(
+        realloc_top_up(0, rent_exempt_min(0), 100),
+        Ok(funded - rent_exempt_min(0))
+    );
+    // Already funded for the new size.
+    assert_eq!(realloc_top_up(0, funded, 100), Ok(0));
+    assert_eq!(realloc_top_up(0, funded + 1, 100), Ok(0));
+    // Shrinking never needs a top-up.
+    assert_eq!(realloc_top_up(100, funded, 50), Ok(0));
+}
+
+#[test]
+fn realloc_rejects_growth_over_cap() {
+    assert!(realloc_top_up(64, u64::MAX, 64 + MAX_PERMITTED_DATA_INCREASE).is_ok());
+    assert_eq!(
+        realloc_top_up(64, u64::MAX, 64 + MAX_PERMITTED_DATA_INCREASE + 1),
+        Err(ProgramError::InvalidRealloc)
+    );
+    assert_eq!(
+        realloc_top_up(MAX_PERMITTED_DATA_LENGTH, u64::MAX, MAX_PERMITTED_DATA_LENGTH + 1),
+        Err(ProgramError::InvalidRealloc)
+    );
+}
+
+// ── Bump tables ──────────────────────────────────────────────────────────────
+
+#[test]
+fn read_bumps_reads_contiguous_table() {
+    let data = [0xAA, 254, 253, 255, 0xBB];
+    let bumps: [u8; 3] = read_bumps(&data, 1).unwrap();
+    assert_eq!(bumps, [254, 253, 255]);
+    let empty: [u8; 0] = read_bumps(&data, 5).unwrap();
+    assert_eq!(empty, []);
+}
+
+#[test]
+fn read_bumps_rejects_out_of_bounds() {
+    let data = [1u8, 2, 3];
+    assert_eq!(read_bumps::<2>(&data, 2), Err(ProgramError::AccountDataTooSmall));
+    assert_eq!(read_bumps::<1>(&data, usize::MAX), Err(ProgramError::AccountDataTooSmall));
+}
+
+// ── Tamper checks ────────────────────────────────────────────────────────────
+
+#[test]
+fn fingerprint_matches_fnv1a_reference() {
+    assert_eq!(data_fingerprint(&[]), 0xcbf2_9ce4_8422_2325);
+    assert_eq!(data_fingerprint(b"a"), 0xaf63_dc4c_8601_ec8c);
+    assert_eq!(data_fingerprint(b"foobar"), 0x85944171f73967e8);
+}
+
+#[test]
+fn check_unchanged_passes_on_untouched_data() {
+    let data = [7u8; 64];
+    let fp = data_fingerprint(&data);
+    assert!(check_unchanged(&data, fp).is_ok());
+}
+
+#[test]
+fn check_unchanged_detects_mutation() {
+    let mut data = [7u8; 64];
+    let fp = data_fingerprint(&data);
+    data[40] ^= 1;
+    assert_eq!(check_unchanged(&data, fp), Err(ProgramError::InvalidAccountData));
+    data[40] ^= 1;
+    assert!(check_unchanged(&data, fp).is_ok());
+    // Length changes are caught too.
+    assert!(check_unchanged(&data[..63], fp).is_err());
+}
@@ -0,0 +1,190 @@
+//! SliceCursor / DataWriter tests.
+
+use core::cell::{Ref, RefCell};
+
+use jiminy_core::account::{DataWriter, FixedLayout, PayloadRef, Pod, SliceCursor};
+use jiminy_core::{Address, ProgramError};
+
+#[test]
+fn read_tag_accepts_up_to_max() {
+    let mut cur = SliceCursor::new(&[0, 2, 3]);
+    assert_eq!(cur.read_tag(2), Ok(0));
+    assert_eq!(cur.read_tag(2), Ok(2));
+    assert_eq!(cur.read_tag(2), Err(ProgramError::InvalidInstructionData));
+    assert_eq!(cur.read_tag(2), Err(ProgramError::AccountDataTooSmall));
+}
+
+#[test]
+fn read_bool_strict_rejects_non_canonical_bytes() {
+    let mut cur = SliceCursor::new(&[0, 1, 2]);
+    assert_eq!(cur.read_bool_strict(), Ok(false));
+    assert_eq!(cur.read_bool_strict(), Ok(true));
+    assert_eq!(cur.read_bool_strict(), Err(ProgramError::InvalidAccountData));
+    assert_eq!(SliceCursor::new(&[0xFF]).read_bool(), Ok(true));
+}
+
+#[test]
+fn read_len_prefixed_bytes_borrows_input() {
+    let data = [3, 0, 0, 0, b'a', b'b', b'c', 9];
+    let mut cur = SliceCursor::new(&data);
+    assert_eq!(cur.read_len_prefixed_bytes(), Ok(&b"abc"[..]));
+    assert_eq!(cur.read_u8(), Ok(9));
+}
+
+#[test]
+fn read_len_prefixed_bytes_rejects_overlong_length() {
+    let data = [4, 0, 0, 0, 1, 2, 3];
+    let mut cur = SliceCursor::new(&data);
+    assert_eq!(cur.read_len_prefixed_bytes(), Err(ProgramError::AccountDataTooSmall));
+}
+
+#[test]
+fn read_len_prefixed_addresses_yields_each_key() {
+    let mut data = vec![2, 0, 0, 0];
+    data.extend_from_slice(&[1; 32]);
+    data.extend_from_slice(&[2; 32]);
+    let mut cur = SliceCursor::new(&data);
+    let keys: Vec<Address> = cur.read_len_prefixed_addresses().unwrap().collect();
+    assert_eq!(keys, [Address::new_from_array([1; 32]), Address::new_from_array([2; 32])]);
+    assert_eq!(cur.remaining(), 0);
+
+    let mut short = SliceCursor::new(&data[..40]);
+    assert!(short.read_len_prefixed_addresses().is_err());
+}
+
+#[test]
+fn len_prefixed_bytes_round_trip() {
+    let mut buf = [0u8; 16];
+    let mut w = DataWriter::new(&mut buf);
+    w.write_len_prefixed_bytes(b"label").unwrap();
+    w.write_len_prefixed_bytes(b"").unwrap();
+    assert_eq!(w.written(), 13);
+    assert_eq!(w.write_len_prefixed_bytes(b"x"), Err(ProgramError::AccountDataTooSmall));
+    assert_eq!(w.written(), 13);
+
+    let mut cur = SliceCursor::new(&buf);
+    assert_eq!(cur.read_len_prefixed_bytes(), Ok(&b"label"[..]));
+    assert_eq!(cur.read_len_prefixed_bytes(), Ok(&b""[..]));
+}
+
+#[test]
+fn read_address_ref_borrows_without_copy() {
+    let mut data = [7u8; 33];
+    data[32] = 1;
+    let mut cur = SliceCursor::new(&data);
+    let key = cur.read_address_ref().unwrap();
+    assert_eq!(*key, Address::new_from_array([7; 32]));
+    assert_eq!(key as *const Address as *const u8, data.as_ptr());
+    assert_eq!(cur.read_address_ref(), Err(ProgramError::AccountDataTooSmall));
+    assert_eq!(cur.read_u8(), Ok(1));
+}
+
+#[test]
+fn finish_requires_full_consumption() {
+    let data = [1u8, 2, 3];
+    let mut cur = SliceCursor::new(&data);
+    cur.read_u8().unwrap();
+    assert_eq!(cur.finish(), Err(ProgramError::InvalidAccountData));
+
+    let mut cur = SliceCursor::new(&data);
+    cur.skip(3).unwrap();
+    assert_eq!(cur.finish(), Ok(()));
+}
+
+#[test]
+fn payload_ref_starts_at_offset() {
+    let cell = RefCell::new([9u8, 1, 0, 0, 0, 0, 0, 0, 0]);
+    let payload = PayloadRef::new(Ref::map(cell.borrow(), |d| &d[..]), 1).unwrap();
+    assert_eq!(payload.bytes().len(), 8);
+    assert_eq!(payload.cursor().read_u64(), Ok(1));
+    assert_eq!(payload.cursor().read_u64(), Ok(1));
+    assert!(cell.try_borrow_mut().is_err());
+    drop(payload);
+    assert!(cell.try_borrow_mut().is_ok());
+
+    let past_end = PayloadRef::new(Ref::map(cell.borrow(), |d| &d[..]), 10);
+    assert!(matches!(past_end, Err(ProgramError::AccountDataTooSmall)));
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Pair {
+    a: u32,
+    b: u32,
+}
+
+unsafe impl Pod for Pair {}
+impl FixedLayout for Pair {
+    const SIZE: usize = 8;
+}
+
+#[repr(C, align(8))]
+struct Aligned([u8; 24]);
+
+#[test]
+fn read_pod_checks_alignment() {
+    let mut buf = Aligned([0; 24]);
+    buf.0[0..4].copy_from_slice(&1u32.to_le_bytes());
+    buf.0[4..8].copy_from_slice(&2u32.to_le_bytes());
+    buf.0[9..13].copy_from_slice(&3u32.to_le_bytes());
+    buf.0[13..17].copy_from_slice(&4u32.to_le_bytes());
+
+    let mut cur = SliceCursor::new(&buf.0);
+    assert_eq!(cur.read_pod::<Pair>(), Ok(&Pair { a: 1, b: 2 }));
+    cur.skip(1).unwrap();
+    assert_eq!(cur.read_pod::<Pair>(), Err(ProgramError::InvalidAccountData));
+    assert_eq!(cur.position(), 9);
+    assert_eq!(cur.read_pod_unaligned::<Pair>(), Ok(Pair { a: 3, b: 4 }));
+    assert_eq!(cur.read_pod_unaligned::<Pair>(), Err(ProgramError::AccountDataTooSmall));
+}
+
+#[test]
+fn with_capacity_caps_writes_and_finish_checks_length() {
+    let mut buf = [0u8; 16];
+    assert_eq!(
+        DataWriter::with_capacity(&mut buf, 17).err(),
+        Some(ProgramError::AccountDataTooSmall)
+    );
+
+    let mut w = DataWriter::with_capacity(&mut buf, 9).unwrap();
+    assert_eq!(w.capacity(), 9);
+    w.write_u64(7).unwrap();
+    assert_eq!(w.write_u16(1), Err(ProgramError::AccountDataTooSmall));
+    w.write_u8(1).unwrap();
+    assert_eq!(w.write_u8(2), Err(ProgramError::AccountDataTooSmall));
+    w.finish().unwrap();
+
+    let mut w = DataWriter::with_capacity(&mut buf, 9).unwrap();
+    w.write_u64(7).unwrap();
+    assert_eq!(w.finish(), Err(ProgramError::InvalidAccountData));
+}
+
+#[test]
+fn iter_records_yields_whole_records_and_advances() {
+    let data = [9, 1, 2, 3, 4, 5, 6, 7];
+    let mut cur = SliceCursor::new(&data);
+    assert_eq!(cur.read_u8(), Ok(9));
+    {
+        let mut records = cur.iter_records::<3>();
+        assert_eq!(records.next(), Some(&[1, 2, 3]));
+        assert_eq!(records.next(), Some(&[4, 5, 6]));
+        assert_eq!(records.next(), None);
+    }
+    assert_eq!(cur.position(), 7);
+    assert_eq!(cur.remaining(), 1);
+    assert_eq!(cur.iter_records::<0>().count(), 0);
+}
+
+#[test]
+fn sub_cursor_is_bounded_and_advances_parent() {
+    let data = [1, 0, 2, 0, 7];
+    let mut cur = SliceCursor::new(&data);
+    let mut inner = cur.sub(4).unwrap();
+    assert_eq!(inner.read_u16(), Ok(1));
+    assert_eq!(inner.read_u32(), Err(ProgramError::AccountDataTooSmall));
+    assert_eq!(inner.read_u16(), Ok(2));
+    assert_eq!(inner.finish(), Ok(()));
+    assert_eq!(cur.read_u8(), Ok(7));
+    assert_eq!(cur.sub(1).err(), Some(ProgramError::AccountDataTooSmall));
+    assert_eq!(cur.sub(0).map(|c| c.remaining()), Ok(0));
+}
@@ -0,0 +1,53 @@
+//! JiminyError code mapping and the granular-errors switch.
+
+use jiminy_core::check::{check_discriminator, check_size, check_version};
+use jiminy_core::error::{JiminyError, JIMINY_ERROR_BASE};
+use jiminy_core::ProgramError;
+
+const ALL: [JiminyError; 9] = [
+    JiminyError::WrongDiscriminator,
+    JiminyError::AccountTooSmall,
+    JiminyError::NotRentExempt,
+    JiminyError::InsufficientLamports,
+    JiminyError::SameAccount,
+    JiminyError::NotWritable,
+    JiminyError::KeyMismatch,
+    JiminyError::VersionTooLow,
+    JiminyError::NotClosed,
+];
+
+#[test]
+fn codes_are_sequential_from_base() {
+    let mut i = 0;
+    while i < ALL.len() {
+        assert_eq!(ALL[i].code(), JIMINY_ERROR_BASE + i as u32);
+        assert_eq!(JiminyError::from_code(ALL[i].code()), Some(ALL[i]));
+        i += 1;
+    }
+    assert_eq!(JiminyError::from_code(JIMINY_ERROR_BASE + ALL.len() as u32), None);
+    assert_eq!(JiminyError::from_code(0), None);
+}
+
+#[test]
+fn converts_to_custom() {
+    let err: ProgramError = JiminyError::NotRentExempt.into();
+    assert_eq!(err, ProgramError::Custom(JIMINY_ERROR_BASE + 2));
+}
+
+#[cfg(not(feature = "granular-errors"))]
+#[test]
+fn checks_return_plain_errors_by_default() {
+    let data = [3u8, 1];
+    assert_eq!(check_discriminator(&data, 4), Err(ProgramError::InvalidAccountData));
+    assert_eq!(check_size(&data, 3), Err(ProgramError::AccountDataTooSmall));
+    assert_eq!(check_version(&data, 2), Err(ProgramError::InvalidAccountData));
+}
+
+#[cfg(feature = "granular-errors")]
+#[test]
+fn checks_return_granular_codes() {
+    let data = [3u8, 1];
+    assert_eq!(check_discriminator(&data, 4), Err(JiminyError::WrongDiscriminator.into()));
+    assert_eq!(check_size(&data, 3), Err(JiminyError::AccountTooSmall.into()));
+    assert_eq!(check_version(&data, 2), Err(JiminyError::VersionTooLow.into()));
+}
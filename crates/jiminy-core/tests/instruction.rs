@@ -0,0 +1,39 @@
+//! Sysvar Instructions parsing tests.
+
+use jiminy_core::instruction::{current_index, program_id_at, sibling_program_id};
+use jiminy_core::{Address, ProgramError};
+
+/// Build sysvar data for instructions with no accounts and no data.
+fn sysvar(programs: &[[u8; 32]], current: u16) -> Vec<u8> {
+    let n = programs.len();
+    let mut out = Vec::new();
+    out.extend_from_slice(&(n as u16).to_le_bytes());
+    let first = 2 + 2 * n;
+    for i in 0..n {
+        out.extend_from_slice(&((first + i * 36) as u16).to_le_bytes());
+    }
+    for p in programs {
+        out.extend_from_slice(&0u16.to_le_bytes());
+        out.extend_from_slice(p);
+        out.extend_from_slice(&0u16.to_le_bytes());
+    }
+    out.extend_from_slice(&current.to_le_bytes());
+    out
+}
+
+#[test]
+fn sibling_program_id_is_relative_to_current() {
+    let data = sysvar(&[[1; 32], [2; 32], [3; 32]], 1);
+    assert_eq!(current_index(&data), Ok(1));
+    assert_eq!(program_id_at(&data, 1), Ok(Address::new_from_array([2; 32])));
+    assert_eq!(sibling_program_id(&data, -1), Ok(Address::new_from_array([1; 32])));
+    assert_eq!(sibling_program_id(&data, 0), Ok(Address::new_from_array([2; 32])));
+    assert_eq!(sibling_program_id(&data, 1), Ok(Address::new_from_array([3; 32])));
+}
+
+#[test]
+fn sibling_program_id_rejects_out_of_range() {
+    let data = sysvar(&[[1; 32], [2; 32]], 0);
+    assert_eq!(sibling_program_id(&data, -1), Err(ProgramError::InvalidAccountData));
+    assert_eq!(sibling_program_id(&data, 2), Err(ProgramError::InvalidAccountData));
+}
@@ -1,11 +1,15 @@
 //! Macro ergonomics and migration-bridge tests.
 
 use jiminy_core::abi::LeU64;
-use jiminy_core::account::{FixedLayout, Pod};
+use jiminy_core::account::{FixedLayout, Pod, SliceCursor};
 use jiminy_core::{
-    assert_legacy_layout, require, require_accounts_ne, require_eq, require_flag, require_gt,
-    require_gte, require_keys_eq, require_keys_neq, require_lt, require_lte, require_neq, Address,
-    ProgramError, ProgramResult,
+    assert_legacy_layout, checked_require_sub, disc_of, dispatch, instruction_dispatch, require,
+    require_account_key_eq, require_account_key_neq, require_accounts_ne, require_eq,
+    require_flag, require_gt, require_gte, require_keys_eq, require_keys_in, require_keys_neq,
+    require_lamports_gte, require_lt, require_lte, require_msg, require_neq, require_owner,
+    require_rent_exempt, require_signer, require_some, require_writable, static_assert,
+    unique_discriminators,
+    Address, ProgramError, ProgramResult,
 };
 
 #[repr(C)]
@@ -62,11 +66,241 @@ fn exercise_key_macros() -> ProgramResult {
     Ok(())
 }
 
+fn account_key_guard(account: &FakeAccount, expected: Address, other: &Address) -> ProgramResult {
+    require_account_key_eq!(account, expected, ProgramError::InvalidArgument);
+    require_account_key_neq!(account, other, ProgramError::IncorrectProgramId,);
+    Ok(())
+}
+
+#[test]
+fn account_key_macros_compare_against_address() {
+    let key = Address::new_from_array([5u8; 32]);
+    let other = Address::new_from_array([6u8; 32]);
+    let acc = FakeAccount(key);
+    assert!(account_key_guard(&acc, key, &other).is_ok());
+    assert_eq!(account_key_guard(&acc, other, &other), Err(ProgramError::InvalidArgument));
+    assert_eq!(account_key_guard(&acc, key, &key), Err(ProgramError::IncorrectProgramId));
+}
+
 #[test]
 fn require_macros_accept_trailing_commas() {
     exercise_require_trailing_commas().unwrap();
 }
 
+struct FakeFlags {
+    signer: bool,
+    writable: bool,
+    owner: Address,
+}
+
+impl FakeFlags {
+    fn is_signer(&self) -> bool {
+        self.signer
+    }
+
+    fn is_writable(&self) -> bool {
+        self.writable
+    }
+
+    fn owned_by(&self, program_id: &Address) -> bool {
+        self.owner == *program_id
+    }
+}
+
+const OWNER: Address = Address::new_from_array([9u8; 32]);
+
+fn exercise_account_flag_macros(acc: &FakeFlags) -> ProgramResult {
+    require_signer!(acc, ProgramError::Custom(1),);
+    require_writable!(acc, ProgramError::Custom(2),);
+    Ok(())
+}
+
+#[test]
+fn account_flag_macros_return_custom_errors() {
+    let ok = FakeFlags { signer: true, writable: true, owner: OWNER };
+    assert_eq!(exercise_account_flag_macros(&ok), Ok(()));
+    let unsigned = FakeFlags { signer: false, writable: true, owner: OWNER };
+    assert_eq!(exercise_account_flag_macros(&unsigned), Err(ProgramError::Custom(1)));
+    let readonly = FakeFlags { signer: true, writable: false, owner: OWNER };
+    assert_eq!(exercise_account_flag_macros(&readonly), Err(ProgramError::Custom(2)));
+}
+
+fn exercise_owner(acc: &FakeFlags) -> ProgramResult {
+    require_owner!(acc, OWNER, ProgramError::Custom(3));
+    require_owner!(acc, &OWNER, ProgramError::Custom(3));
+    Ok(())
+}
+
+#[test]
+fn require_owner_returns_custom_error() {
+    let owned = FakeFlags { signer: false, writable: false, owner: OWNER };
+    assert_eq!(exercise_owner(&owned), Ok(()));
+    let foreign = FakeFlags {
+        signer: false,
+        writable: false,
+        owner: Address::new_from_array([0; 32]),
+    };
+    assert_eq!(exercise_owner(&foreign), Err(ProgramError::Custom(3)));
+}
+
+fn exercise_keys_in(key: &Address) -> ProgramResult {
+    let allowed = [Address::new_from_array([1; 32]), Address::new_from_array([2; 32])];
+    require_keys_in!(key, &allowed, ProgramError::Custom(4),);
+    Ok(())
+}
+
+#[test]
+fn require_keys_in_matches_any_listed_key() {
+    assert_eq!(exercise_keys_in(&Address::new_from_array([1; 32])), Ok(()));
+    assert_eq!(exercise_keys_in(&Address::new_from_array([2; 32])), Ok(()));
+    assert_eq!(
+        exercise_keys_in(&Address::new_from_array([3; 32])),
+        Err(ProgramError::Custom(4))
+    );
+}
+
+fn exercise_require_msg(amount: u64) -> ProgramResult {
+    require_msg!(amount > 0, ProgramError::Custom(6), "amount is zero",);
+    Ok(())
+}
+
+#[test]
+fn require_msg_returns_error_after_logging() {
+    assert_eq!(exercise_require_msg(1), Ok(()));
+    assert_eq!(exercise_require_msg(0), Err(ProgramError::Custom(6)));
+}
+
+struct FakeFunded {
+    lamports: u64,
+    data_len: usize,
+}
+
+impl FakeFunded {
+    fn lamports(&self) -> u64 {
+        self.lamports
+    }
+
+    fn data_len(&self) -> usize {
+        self.data_len
+    }
+}
+
+fn exercise_funding(acc: &FakeFunded, min: u64) -> ProgramResult {
+    require_lamports_gte!(acc, min, ProgramError::Custom(7));
+    require_rent_exempt!(acc, ProgramError::Custom(8),);
+    Ok(())
+}
+
+#[test]
+fn funding_macros_return_custom_errors() {
+    let rent = jiminy_core::check::rent_exempt_min(100);
+    let funded = FakeFunded { lamports: rent, data_len: 100 };
+    assert_eq!(exercise_funding(&funded, rent), Ok(()));
+    assert_eq!(exercise_funding(&funded, rent + 1), Err(ProgramError::Custom(7)));
+    let underfunded = FakeFunded { lamports: rent - 1, data_len: 100 };
+    assert_eq!(exercise_funding(&underfunded, 0), Err(ProgramError::Custom(8)));
+}
+
+fn rent_check_evaluations(acc: &FakeFunded) -> u32 {
+    let mut evaluations = 0;
+    let mut check = || -> ProgramResult {
+        require_rent_exempt!(
+            {
+                evaluations += 1;
+                acc
+            },
+            ProgramError::Custom(8)
+        );
+        Ok(())
+    };
+    assert_eq!(check(), Ok(()));
+    evaluations
+}
+
+#[test]
+fn require_rent_exempt_evaluates_account_once() {
+    let funded = FakeFunded { lamports: u64::MAX, data_len: 100 };
+    assert_eq!(rent_check_evaluations(&funded), 1);
+}
+
+fn withdraw(balance: u64, amount: u64) -> Result<u64, ProgramError> {
+    Ok(checked_require_sub!(balance, amount, ProgramError::InsufficientFunds))
+}
+
+#[test]
+fn checked_require_sub_yields_difference() {
+    assert_eq!(withdraw(10, 4), Ok(6));
+    assert_eq!(withdraw(4, 4), Ok(0));
+    assert_eq!(withdraw(3, 4), Err(ProgramError::InsufficientFunds));
+}
+
+const IX_A: u8 = 0;
+const IX_B: u8 = 1;
+
+fn handle_a(_: &Address, _: &[u8], ix: &SliceCursor) -> ProgramResult {
+    match ix.data_from_position() {
+        [] => Ok(()),
+        rest => Err(ProgramError::Custom(rest[0] as u32)),
+    }
+}
+
+fn handle_b(_: &Address, _: &[u8], _: &SliceCursor) -> ProgramResult {
+    Err(ProgramError::Custom(100))
+}
+
+fn route_fns(data: &[u8]) -> ProgramResult {
+    let program_id = Address::new_from_array([0; 32]);
+    dispatch! {
+        &program_id, &[], data;
+        IX_A => handle_a,
+        IX_B => handle_b,
+    }
+}
+
+fn route_exprs(data: &[u8]) -> ProgramResult {
+    instruction_dispatch! {
+        (), (), data; ix;
+        IX_A => Err(ProgramError::Custom(ix.remaining() as u32)),
+        2..=4 => Ok(()),
+    }
+}
+
+fn route_literal_exprs(data: &[u8]) -> ProgramResult {
+    instruction_dispatch! {
+        (), (), data;
+        0 => Ok(()),
+        1 => Err(ProgramError::Custom(1)),
+    }
+}
+
+#[test]
+fn instruction_dispatch_routes_by_tag() {
+    assert_eq!(route_fns(&[IX_A]), Ok(()));
+    assert_eq!(route_fns(&[IX_A, 7]), Err(ProgramError::Custom(7)));
+    assert_eq!(route_fns(&[IX_B]), Err(ProgramError::Custom(100)));
+    assert_eq!(route_fns(&[9]), Err(ProgramError::InvalidInstructionData));
+    assert_eq!(route_fns(&[]), Err(ProgramError::AccountDataTooSmall));
+
+    assert_eq!(route_exprs(&[IX_A, 1, 2]), Err(ProgramError::Custom(2)));
+    assert_eq!(route_exprs(&[3]), Ok(()));
+    assert_eq!(route_exprs(&[5]), Err(ProgramError::InvalidInstructionData));
+
+    assert_eq!(route_literal_exprs(&[0, 9]), Ok(()));
+    assert_eq!(route_literal_exprs(&[1]), Err(ProgramError::Custom(1)));
+    assert_eq!(route_literal_exprs(&[2]), Err(ProgramError::InvalidInstructionData));
+}
+
+fn exercise_require_some(value: Option<u64>) -> Result<u64, ProgramError> {
+    let v = require_some!(value, ProgramError::Custom(5));
+    Ok(v + 1)
+}
+
+#[test]
+fn require_some_unwraps_or_returns_error() {
+    assert_eq!(exercise_require_some(Some(41)), Ok(42));
+    assert_eq!(exercise_require_some(None), Err(ProgramError::Custom(5)));
+}
+
 #[test]
 fn key_macros_accept_owned_and_borrowed_addresses() {
     exercise_key_macros().unwrap();
@@ -78,3 +312,89 @@ fn assert_legacy_layout_checks_size_and_traits() {
     assert_eq!(LegacyVaultV1::SIZE, 40);
     assert_eq!(core::mem::align_of::<LegacyVaultV1>(), 1);
 }
+
+const VAULT_DISC: u8 = disc_of!("Vault");
+const ESCROW_DISC: u8 = disc_of!("Escrow");
+const POOL_DISC: u8 = disc_of!("Pool");
+
+unique_discriminators!(VAULT_DISC, ESCROW_DISC, POOL_DISC);
+
+const FAKE_HEADER_LEN: usize = 16;
+const FAKE_VAULT_LEN: usize = FAKE_HEADER_LEN + 8 + 32;
+static_assert!(FAKE_VAULT_LEN == 56);
+static_assert!(FAKE_HEADER_LEN <= FAKE_VAULT_LEN, "header longer than the account",);
+// Braces in the condition must not be read as format placeholders.
+static_assert!({ FAKE_HEADER_LEN } < FAKE_VAULT_LEN);
+
+#[test]
+fn static_assert_works_inside_functions() {
+    static_assert!(core::mem::size_of::<u64>() == 8);
+}
+
+#[test]
+fn disc_of_is_stable_first_byte_of_sha256() {
+    // First non-zero byte of sha256(name); pinned so a hashing change
+    // cannot silently re-tag existing accounts.
+    assert_eq!(VAULT_DISC, 0x5d);
+    assert_eq!(ESCROW_DISC, 0xbd);
+    assert_eq!(POOL_DISC, 0xf1);
+    assert_eq!(disc_of!("Vault"), VAULT_DISC);
+}
+
+// ── flags! ───────────────────────────────────────────────────────────────────
+
+jiminy_core::flags! {
+    /// Test flag set.
+    pub struct TestFlags: u16 {
+        ACCEPTED = 0,
+        CANCELLED = 1,
+        /// High bit of the u16.
+        FROZEN = 15,
+    }
+}
+
+#[test]
+fn flags_masks_from_indexes() {
+    assert_eq!(TestFlags::ACCEPTED.bits(), 0x0001);
+    assert_eq!(TestFlags::CANCELLED.bits(), 0x0002);
+    assert_eq!(TestFlags::FROZEN.bits(), 0x8000);
+    assert_eq!(TestFlags::ALL.bits(), 0x8003);
+    assert_eq!(TestFlags::empty().bits(), 0);
+}
+
+#[test]
+fn flags_insert_remove_toggle() {
+    let mut f = TestFlags::empty();
+    f.insert(TestFlags::ACCEPTED);
+    assert!(f.contains(TestFlags::ACCEPTED));
+    assert!(!f.contains(TestFlags::ACCEPTED.union(TestFlags::CANCELLED)));
+    assert!(f.intersects(TestFlags::ACCEPTED.union(TestFlags::CANCELLED)));
+
+    f.toggle(TestFlags::FROZEN);
+    assert_eq!(f.bits(), 0x8001);
+    f.remove(TestFlags::ACCEPTED);
+    assert_eq!(f, TestFlags::FROZEN);
+    f.toggle(TestFlags::FROZEN);
+    assert_eq!(f, TestFlags::default());
+}
+
+#[test]
+fn flags_is_known_rejects_undeclared_bits() {
+    assert!(TestFlags::from_bits(0x8002).is_known());
+    assert!(!TestFlags::from_bits(0x0004).is_known());
+}
+
+// ── signer_seeds! ────────────────────────────────────────────────────────────
+
+fn signer_count(signers: &[jiminy_core::hopper_runtime::cpi::Signer]) -> usize {
+    signers.len()
+}
+
+#[test]
+fn signer_seeds_binds_signer_with_bump() {
+    let authority = jiminy_core::Address::new_from_array([7; 32]);
+    let bump = 254;
+    jiminy_core::signer_seeds!(vault = [b"vault", authority], bump);
+    jiminy_core::signer_seeds!(config = [b"config"], 255);
+    assert_eq!(signer_count(&[vault, config]), 2);
+}
@@ -0,0 +1,189 @@
+//! Tests for the checked math helpers.
+
+use jiminy_core::math::*;
+use jiminy_core::ProgramError;
+
+// ── Saturating ───────────────────────────────────────────────────────────────
+
+#[test]
+fn saturating_ops_clamp_at_bounds() {
+    assert_eq!(saturating_add(u64::MAX - 1, 5), u64::MAX);
+    assert_eq!(saturating_add(2, 3), 5);
+    assert_eq!(saturating_sub(3, 5), 0);
+    assert_eq!(saturating_sub(5, 3), 2);
+    assert_eq!(saturating_mul(u64::MAX / 2, 3), u64::MAX);
+    assert_eq!(saturating_mul(4, 5), 20);
+}
+
+// ── Division ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn checked_div_and_rem_split_exactly() {
+    assert_eq!(checked_div(1_000_003, 10), Ok(100_000));
+    assert_eq!(checked_rem(1_000_003, 10), Ok(3));
+    assert_eq!(checked_rem(u64::MAX, 1), Ok(0));
+    assert_eq!(checked_rem(7, u64::MAX), Ok(7));
+}
+
+#[test]
+fn checked_div_and_rem_reject_zero_divisor() {
+    assert_eq!(checked_div(1, 0), Err(ProgramError::ArithmeticOverflow));
+    assert_eq!(checked_rem(1, 0), Err(ProgramError::ArithmeticOverflow));
+    assert_eq!(checked_rem(0, 0), Err(ProgramError::ArithmeticOverflow));
+}
+
+// ── Mul-div ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn mul_div_survives_u64_overflow_in_intermediate() {
+    // u64::MAX * 3 overflows u64, but the quotient fits.
+    assert_eq!(checked_mul_div(u64::MAX, 3, 3), Ok(u64::MAX));
+    assert_eq!(checked_mul_div(u64::MAX, u64::MAX, u64::MAX), Ok(u64::MAX));
+    assert_eq!(checked_mul_div_ceil(u64::MAX, 3, 3), Ok(u64::MAX));
+}
+
+#[test]
+fn mul_div_rejects_result_above_u64() {
+    assert_eq!(checked_mul_div(u64::MAX, 2, 1), Err(ProgramError::ArithmeticOverflow));
+    assert_eq!(checked_mul_div_ceil(u64::MAX, 2, 1), Err(ProgramError::ArithmeticOverflow));
+    // 1190112520884487201 * 31 = 2^65 - 1: the floor is exactly u64::MAX
+    // with remainder 1, so only the ceil needs one more unit.
+    let a = 1_190_112_520_884_487_201;
+    assert_eq!(checked_mul_div(a, 31, 2), Ok(u64::MAX));
+    assert_eq!(checked_mul_div_ceil(a, 31, 2), Err(ProgramError::ArithmeticOverflow));
+}
+
+#[test]
+fn mul_div_rejects_zero_denominator() {
+    assert_eq!(checked_mul_div(1, 1, 0), Err(ProgramError::ArithmeticOverflow));
+    assert_eq!(checked_mul_div_ceil(1, 1, 0), Err(ProgramError::ArithmeticOverflow));
+}
+
+#[test]
+fn mul_div_ceil_rounds_up_only_on_remainder() {
+    assert_eq!(checked_mul_div(10, 3, 4), Ok(7));
+    assert_eq!(checked_mul_div_ceil(10, 3, 4), Ok(8));
+    assert_eq!(checked_mul_div_ceil(10, 4, 4), Ok(10));
+    assert_eq!(checked_mul_div_ceil(0, 5, 7), Ok(0));
+}
+
+// ── Basis points ─────────────────────────────────────────────────────────────
+
+#[test]
+fn bps_of_floor_and_ceil() {
+    assert_eq!(bps_of(1_000_000, 30), Ok(3_000));
+    assert_eq!(bps_of(999, 30), Ok(2));
+    assert_eq!(bps_of_ceil(999, 30), Ok(3));
+    assert_eq!(bps_of(1, 1), Ok(0));
+    assert_eq!(bps_of_ceil(1, 1), Ok(1));
+    assert_eq!(bps_of(u64::MAX, MAX_BPS), Ok(u64::MAX));
+    assert_eq!(bps_of_ceil(u64::MAX, MAX_BPS), Ok(u64::MAX));
+}
+
+#[test]
+fn bps_of_rejects_more_than_100_percent() {
+    assert_eq!(bps_of(100, MAX_BPS + 1), Err(ProgramError::InvalidArgument));
+    assert_eq!(bps_of_ceil(100, u16::MAX), Err(ProgramError::InvalidArgument));
+}
+
+// ── Signed casts ─────────────────────────────────────────────────────────────
+
+#[test]
+fn u64_i64_casts_round_trip_in_range() {
+    assert_eq!(u64_to_i64(0), Ok(0));
+    assert_eq!(u64_to_i64(i64::MAX as u64), Ok(i64::MAX));
+    assert_eq!(i64_to_u64(0), Ok(0));
+    assert_eq!(i64_to_u64(i64::MAX), Ok(i64::MAX as u64));
+}
+
+#[test]
+fn u64_i64_casts_reject_out_of_range() {
+    assert_eq!(u64_to_i64(i64::MAX as u64 + 1), Err(ProgramError::ArithmeticOverflow));
+    assert_eq!(u64_to_i64(u64::MAX), Err(ProgramError::ArithmeticOverflow));
+    assert_eq!(i64_to_u64(-1), Err(ProgramError::ArithmeticOverflow));
+    assert_eq!(i64_to_u64(i64::MIN), Err(ProgramError::ArithmeticOverflow));
+}
+
+// ── Powers ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn ten_pow_matches_checked_pow() {
+    let mut d = 0u8;
+    while d <= 19 {
+        assert_eq!(ten_pow(d), checked_pow(10, d as u32));
+        d += 1;
+    }
+    assert_eq!(ten_pow(19), Ok(10_000_000_000_000_000_000));
+}
+
+#[test]
+fn ten_pow_and_checked_pow_reject_overflow() {
+    assert_eq!(ten_pow(20), Err(ProgramError::ArithmeticOverflow));
+    assert_eq!(ten_pow(u8::MAX), Err(ProgramError::ArithmeticOverflow));
+    assert_eq!(checked_pow(10, 20), Err(ProgramError::ArithmeticOverflow));
+    assert_eq!(checked_pow(2, 64), Err(ProgramError::ArithmeticOverflow));
+    assert_eq!(checked_pow(2, 63), Ok(1 << 63));
+}
+
+#[test]
+fn scale_amount_down_uses_table_range() {
+    assert_eq!(scale_amount(1_000_000_000, 9, 6), Ok(1_000_000));
+    assert_eq!(scale_amount_ceil(999, 9, 6), Ok(1));
+    assert_eq!(scale_amount(u64::MAX, 19, 0), Ok(1));
+    assert_eq!(scale_amount(u64::MAX, 20, 0), Err(ProgramError::ArithmeticOverflow));
+}
+
+// ── Signed arithmetic ────────────────────────────────────────────────────────
+
+#[test]
+fn i64_checked_ops_in_range() {
+    assert_eq!(checked_add_i64(1_700_000_000, 3_600), Ok(1_700_003_600));
+    assert_eq!(checked_sub_i64(-5, 10), Ok(-15));
+    assert_eq!(checked_mul_i64(-86_400, 7), Ok(-604_800));
+}
+
+#[test]
+fn i64_checked_ops_reject_overflow() {
+    assert_eq!(checked_add_i64(i64::MAX, 1), Err(ProgramError::ArithmeticOverflow));
+    assert_eq!(checked_add_i64(i64::MIN, -1), Err(ProgramError::ArithmeticOverflow));
+    assert_eq!(checked_sub_i64(i64::MIN, 1), Err(ProgramError::ArithmeticOverflow));
+    assert_eq!(checked_sub_i64(0, i64::MIN), Err(ProgramError::ArithmeticOverflow));
+    assert_eq!(checked_mul_i64(i64::MIN, -1), Err(ProgramError::ArithmeticOverflow));
+}
+
+// ── Q64.64 ───────────────────────────────────────────────────────────────────
+
+const Q64_ONE: u128 = 1 << 64;
+const Q64_HALF: u128 = 1 << 63;
+
+#[test]
+fn q64_int_conversions_round_trip() {
+    assert_eq!(int_to_q64(1), Q64_ONE);
+    assert_eq!(q64_to_int(int_to_q64(u64::MAX)), u64::MAX);
+    assert_eq!(q64_to_int(Q64_ONE + Q64_HALF), 1);
+    assert_eq!(q64_to_int(Q64_HALF), 0);
+}
+
+#[test]
+fn q64_mul_known_products() {
+    // 1.5 * 2.0 = 3.0
+    assert_eq!(q64_mul(Q64_ONE + Q64_HALF, int_to_q64(2)), Ok(int_to_q64(3)));
+    // 0.5 * 0.5 = 0.25
+    assert_eq!(q64_mul(Q64_HALF, Q64_HALF), Ok(1 << 62));
+    // 2^32 * 2^31 = 2^63
+    assert_eq!(q64_mul(int_to_q64(1 << 32), int_to_q64(1 << 31)), Ok(int_to_q64(1 << 63)));
+    // Smallest fraction squared truncates to zero.
+    assert_eq!(q64_mul(1, 1), Ok(0));
+    assert_eq!(q64_mul(u128::MAX, 0), Ok(0));
+    assert_eq!(q64_mul(u128::MAX, Q64_ONE), Ok(u128::MAX));
+}
+
+#[test]
+fn q64_mul_rejects_overflow() {
+    assert_eq!(
+        q64_mul(int_to_q64(1 << 32), int_to_q64(1 << 32)),
+        Err(ProgramError::ArithmeticOverflow)
+    );
+    assert_eq!(q64_mul(u128::MAX, u128::MAX), Err(ProgramError::ArithmeticOverflow));
+    assert_eq!(q64_mul(u128::MAX, Q64_ONE + 1), Err(ProgramError::ArithmeticOverflow));
+}
@@ -0,0 +1,116 @@
+//! Tests for off-chain PDA derivation.
+//!
+//! Expected addresses were computed independently (SHA-256 + ed25519
+//! decompression check) and, for USDC, match the live metadata account.
+
+use jiminy_core::check::pda::{append_bump, derive_address_const, find_program_address};
+use jiminy_core::hopper_runtime::address::MAX_SEEDS;
+use jiminy_core::ProgramError;
+use jiminy_core::signer::PdaSigner;
+use jiminy_core::{find_pda, Address};
+
+const USDC_MINT: [u8; 32] = [
+    198, 250, 122, 243, 190, 219, 173, 58, 61, 101, 243, 106, 171, 201, 116, 49, 177, 187, 228,
+    194, 210, 246, 224, 228, 124, 166, 2, 3, 69, 47, 93, 97,
+];
+/// `5x38Kp4hvdomTCnCrAny4UtMUt5rQBdB6px2K1Ui45Wq`
+const USDC_METADATA: [u8; 32] = [
+    73, 136, 24, 173, 152, 105, 114, 247, 124, 17, 186, 135, 135, 129, 147, 103, 214, 46, 137,
+    36, 246, 219, 107, 107, 211, 125, 199, 76, 47, 217, 97, 58,
+];
+
+#[test]
+fn finds_canonical_bump_below_255() {
+    let program_id = Address::new_from_array([7; 32]);
+    let (pda, bump) = find_program_address(&[b"vault"], &program_id);
+    assert_eq!(bump, 254);
+    assert_eq!(
+        pda.to_bytes(),
+        [
+            63, 204, 154, 156, 44, 128, 245, 147, 167, 68, 46, 138, 245, 247, 248, 127, 186, 116,
+            98, 91, 132, 36, 242, 186, 228, 156, 78, 10, 164, 22, 194, 236,
+        ]
+    );
+}
+
+#[test]
+fn skips_multiple_on_curve_bumps() {
+    let (pda, bump) = find_program_address(&[&[6]], &Address::new_from_array([0; 32]));
+    assert_eq!(bump, 253);
+    assert_eq!(pda.to_bytes(), derive_address_const(&[&[6]], Some(253), &[0; 32]));
+}
+
+#[test]
+fn find_pda_macro_runs_off_chain() {
+    let program_id = Address::new_from_array([7; 32]);
+    let (pda, bump) = find_pda!(&program_id, b"vault");
+    assert_eq!((pda, bump), find_program_address(&[b"vault"], &program_id));
+}
+
+#[cfg(feature = "programs")]
+#[test]
+fn matches_live_usdc_metadata_pda() {
+    use jiminy_core::programs::METADATA;
+    let mint = Address::new_from_array(USDC_MINT);
+    let (pda, bump) = find_pda!(&METADATA, b"metadata", METADATA, mint);
+    assert_eq!(pda.to_bytes(), USDC_METADATA);
+    assert_eq!(bump, 255);
+}
+
+#[cfg(feature = "host-sim")]
+#[test]
+fn host_sim_derive_address_matches_search() {
+    use jiminy_core::check::pda::derive_address;
+    let program_id = Address::new_from_array([7; 32]);
+    let (pda, bump) = find_program_address(&[b"vault"], &program_id);
+    assert_eq!(derive_address(&[b"vault"], Some(bump), program_id.as_array()), pda.to_bytes());
+}
+
+#[cfg(all(feature = "host-sim", feature = "programs"))]
+#[test]
+fn host_sim_derives_metadata_pda_off_chain() {
+    use jiminy_core::check::pda::derive_metadata_pda;
+    let (pda, bump) = derive_metadata_pda(&Address::new_from_array(USDC_MINT)).unwrap();
+    assert_eq!(pda.to_bytes(), USDC_METADATA);
+    assert_eq!(bump, 255);
+}
+
+#[test]
+fn append_bump_appends_after_seeds() {
+    let bump = [254u8];
+    let mut buf: [&[u8]; MAX_SEEDS] = [&[]; MAX_SEEDS];
+    let seeds = append_bump(&[b"vault", b"alice"], &bump, &mut buf).unwrap();
+    assert_eq!(seeds, &[&b"vault"[..], &b"alice"[..], &[254u8][..]]);
+
+    let full: [&[u8]; MAX_SEEDS] = [b"s"; MAX_SEEDS];
+    let mut buf: [&[u8]; MAX_SEEDS] = [&[]; MAX_SEEDS];
+    let max = append_bump(&full[..MAX_SEEDS - 1], &bump, &mut buf);
+    assert_eq!(max.map(|s| s.len()), Ok(MAX_SEEDS));
+    let mut buf: [&[u8]; MAX_SEEDS] = [&[]; MAX_SEEDS];
+    assert_eq!(append_bump(&full, &bump, &mut buf), Err(ProgramError::InvalidSeeds));
+}
+
+// ── PdaSigner ────────────────────────────────────────────────────────────────
+
+#[test]
+fn pda_signer_appends_own_bump() {
+    let authority = [7u8; 32];
+    let signer = PdaSigner::new([b"vault", &authority], 254);
+    assert_eq!(signer.bump(), 254);
+
+    let mut buf = [&[][..]; MAX_SEEDS];
+    let seeds = signer.seeds(&mut buf);
+    assert_eq!(seeds, &[&b"vault"[..], &authority[..], &[254u8][..]]);
+
+    // Seeds borrow from the signer, not the buffer, so the buffer is free
+    // for reuse while one is still held.
+    let bump_seed = seeds[2];
+    assert_eq!(signer.seeds(&mut buf).len(), 3);
+    assert_eq!(bump_seed, &[254u8][..]);
+}
+
+#[test]
+fn pda_signer_with_signer_returns_closure_result() {
+    let signer = PdaSigner::new([b"config"], 255);
+    assert_eq!(signer.with_signer(|_| 42), 42);
+}
@@ -0,0 +1,46 @@
+//! Program id lookup tests.
+
+#![cfg(feature = "programs")]
+
+use jiminy_core::programs::{self, is_loader, is_sysvar, is_token_program, program_name};
+use jiminy_core::Address;
+
+#[test]
+fn is_sysvar_matches_only_sysvars() {
+    assert!(is_sysvar(&programs::SYSVAR_CLOCK));
+    assert!(is_sysvar(&programs::SYSVAR_RENT));
+    assert!(is_sysvar(&programs::SYSVAR_INSTRUCTIONS));
+    assert!(is_sysvar(&programs::SYSVAR_SLOT_HASHES));
+    assert!(is_sysvar(&programs::SYSVAR_EPOCH_SCHEDULE));
+    assert!(!is_sysvar(&programs::SYSTEM));
+    assert!(!is_sysvar(&programs::TOKEN));
+    assert!(!is_sysvar(&Address::new_from_array([6; 32])));
+}
+
+#[test]
+fn program_name_covers_known_ids() {
+    assert_eq!(program_name(&programs::TOKEN), Some("SPL Token"));
+    assert_eq!(program_name(&programs::STAKE), Some("Stake Program"));
+    assert_eq!(program_name(&programs::SYSVAR_CLOCK), Some("Sysvar: Clock"));
+    assert_eq!(program_name(&Address::new_from_array([6; 32])), None);
+}
+
+#[test]
+fn is_loader_matches_only_loaders() {
+    assert!(is_loader(&programs::BPF_LOADER));
+    assert!(is_loader(&programs::BPF_LOADER_2));
+    assert!(is_loader(&programs::BPF_LOADER_DEPRECATED));
+    assert!(is_loader(&programs::LOADER_V4));
+    assert!(!is_loader(&programs::SYSTEM));
+    assert!(!is_loader(&programs::TOKEN));
+    assert_eq!(program_name(&programs::BPF_LOADER_2), Some("BPF Loader 2"));
+}
+
+#[test]
+fn is_token_program_matches_only_token_programs() {
+    assert!(is_token_program(&programs::TOKEN));
+    assert!(is_token_program(&programs::TOKEN_2022));
+    assert!(!is_token_program(&programs::SYSTEM));
+    assert!(!is_token_program(&programs::ASSOCIATED_TOKEN));
+    assert!(!is_token_program(&Address::new_from_array([6; 32])));
+}
@@ -0,0 +1,49 @@
+//! Tests for the time and rate-limit helpers.
+
+use jiminy_core::time::*;
+use jiminy_core::ProgramError;
+
+// ── Rate limiting ────────────────────────────────────────────────────────────
+
+const LIMIT: RateLimiter = RateLimiter::new(100, 2);
+
+#[test]
+fn rate_limiter_enforces_cap_within_window() {
+    let mut data = [0u8; 4 + RATE_LIMITER_LEN];
+    LIMIT.try_consume(&mut data, 4, 1_000).unwrap();
+    LIMIT.try_consume(&mut data, 4, 1_050).unwrap();
+    let before = data;
+    assert_eq!(LIMIT.try_consume(&mut data, 4, 1_099), Err(ProgramError::InvalidArgument));
+    // A rejected attempt leaves the state untouched.
+    assert_eq!(data, before);
+    assert_eq!(&data[..4], &[0u8; 4]);
+}
+
+#[test]
+fn rate_limiter_resets_after_window() {
+    let mut data = [0u8; RATE_LIMITER_LEN];
+    LIMIT.try_consume(&mut data, 0, 1_000).unwrap();
+    LIMIT.try_consume(&mut data, 0, 1_001).unwrap();
+    assert!(LIMIT.try_consume(&mut data, 0, 1_099).is_err());
+
+    LIMIT.try_consume(&mut data, 0, 1_100).unwrap();
+    assert_eq!(&data[0..8], &1_100u64.to_le_bytes());
+    assert_eq!(&data[8..16], &1u64.to_le_bytes());
+}
+
+#[test]
+fn rate_limiter_rejects_short_data() {
+    let mut data = [0u8; RATE_LIMITER_LEN];
+    assert_eq!(LIMIT.try_consume(&mut data, 1, 0), Err(ProgramError::AccountDataTooSmall));
+    assert_eq!(
+        LIMIT.try_consume(&mut data, usize::MAX, 0),
+        Err(ProgramError::AccountDataTooSmall)
+    );
+}
+
+#[test]
+fn rate_limiter_zero_cap_always_rejects() {
+    let mut data = [0u8; RATE_LIMITER_LEN];
+    let closed = RateLimiter::new(10, 0);
+    assert_eq!(closed.try_consume(&mut data, 0, 500), Err(ProgramError::InvalidArgument));
+}
@@ -9,9 +9,11 @@ use hopper_runtime::ProgramError;
 
 /// Split `total` proportionally by `shares`, writing results to `out`.
 ///
-/// Uses the largest-remainder method: floor-divide first, then hand out
-/// the leftover one unit at a time to the first N recipients. Guarantees
-/// `out[0] + out[1] + ... == total`.
+/// Each part is `floor(total * shares[i] / sum(shares))`, computed in
+/// u128. The leftover (always fewer units than there are nonzero shares)
+/// goes one unit each to the first recipients with a nonzero share, so
+/// the result is deterministic and a zero-share recipient gets exactly 0.
+/// Guarantees `out[0] + out[1] + ... == total`.
 ///
 /// `shares` and `out` must have the same length.
 ///
@@ -57,18 +59,18 @@ pub fn proportional_split(
         i += 1;
     }
 
-    // Second pass: distribute remainder one unit at a time
+    // Second pass: one leftover unit each to the first nonzero shares.
+    // Each floor loses less than one unit, so a single pass covers it.
     let mut remainder = total
         .checked_sub(distributed)
         .ok_or(ProgramError::ArithmeticOverflow)?;
     let mut j = 0;
-    while remainder > 0 {
-        out[j] += 1;
-        remainder -= 1;
-        j += 1;
-        if j >= out.len() {
-            j = 0;
+    while remainder > 0 && j < out.len() {
+        if shares[j] != 0 {
+            out[j] += 1;
+            remainder -= 1;
         }
+        j += 1;
     }
 
     Ok(())
@@ -0,0 +1,48 @@
+//! Tests for the proportional split.
+
+use jiminy_distribute::hopper_runtime::ProgramError;
+use jiminy_distribute::proportional_split;
+
+fn split<const N: usize>(total: u64, shares: [u64; N]) -> [u64; N] {
+    let mut out = [0u64; N];
+    proportional_split(total, &shares, &mut out).unwrap();
+    out
+}
+
+#[test]
+fn zero_share_first_gets_nothing() {
+    // Floors are 0, 3, 3; the 1-unit remainder must skip index 0.
+    assert_eq!(split(7, [0, 1, 1]), [0, 4, 3]);
+    assert_eq!(split(1, [0, 5]), [0, 1]);
+}
+
+#[test]
+fn parts_always_sum_to_total() {
+    let cases: [(u64, [u64; 4]); 5] = [
+        (1_000_003, [50, 30, 20, 0]),
+        (u64::MAX, [1, 1, 1, 1]),
+        (u64::MAX, [u64::MAX, 1, 0, 7]),
+        (5, [3, 3, 3, 3]),
+        (0, [1, 2, 3, 4]),
+    ];
+    for (total, shares) in cases {
+        let out = split(total, shares);
+        assert_eq!(out.iter().map(|&p| p as u128).sum::<u128>(), total as u128);
+    }
+}
+
+#[test]
+fn remainder_goes_to_first_nonzero_shares_in_order() {
+    // Floors are 1 each, remainder 2: indexes 0 and 1 get the extra unit.
+    assert_eq!(split(5, [1, 1, 1]), [2, 2, 1]);
+    // Zero shares are skipped but the order of the rest is kept.
+    assert_eq!(split(5, [0, 1, 0, 1, 1]), [0, 2, 0, 2, 1]);
+}
+
+#[test]
+fn rejects_bad_inputs() {
+    let mut out = [0u64; 2];
+    assert_eq!(proportional_split(10, &[0, 0], &mut out), Err(ProgramError::InvalidArgument));
+    assert_eq!(proportional_split(10, &[1], &mut out), Err(ProgramError::InvalidArgument));
+    assert_eq!(proportional_split(10, &[], &mut []), Err(ProgramError::InvalidArgument));
+}
@@ -0,0 +1,122 @@
+//! Associated Token Account program CPI builders.
+//!
+//! Create the canonical ATA for a `wallet` + `mint` pair without pulling
+//! in the ATA crate. The passed `ata` account is checked against
+//! [`derive_ata_with_program`] before invoking, so a wrong account fails
+//! here with `InvalidSeeds` rather than inside the ATA program.
+//!
+//! `token_program` must be the program that owns `mint` (SPL Token or
+//! Token-2022); it is also a seed of the ATA address.
+//!
+//! ```rust,ignore
+//! ata_cpi::create_idempotent(payer, user_ata, user, mint, system_program, token_program)?;
+//! ```
+
+use hopper_runtime::cpi::{invoke_signed, Signer};
+use hopper_runtime::instruction::{InstructionAccount, InstructionView};
+use hopper_runtime::{AccountView, ProgramError, ProgramResult};
+use jiminy_core::check::pda::derive_ata_with_program;
+use jiminy_core::programs::ASSOCIATED_TOKEN;
+
+/// `Create` instruction tag (fails if the account already exists).
+const IX_CREATE: u8 = 0;
+/// `CreateIdempotent` instruction tag (no-op if the ATA already exists).
+const IX_CREATE_IDEMPOTENT: u8 = 1;
+
+/// Create the ATA for `wallet` + `mint`, funded by `payer`.
+///
+/// Fails if the account already exists; see [`create_idempotent`].
+#[inline(always)]
+pub fn create(
+    payer: &AccountView,
+    ata: &AccountView,
+    wallet: &AccountView,
+    mint: &AccountView,
+    system_program: &AccountView,
+    token_program: &AccountView,
+) -> ProgramResult {
+    invoke_create(IX_CREATE, payer, ata, wallet, mint, system_program, token_program, &[])
+}
+
+/// [`create`] with PDA signer seeds for `payer`.
+#[allow(clippy::too_many_arguments)]
+#[inline(always)]
+pub fn create_signed(
+    payer: &AccountView,
+    ata: &AccountView,
+    wallet: &AccountView,
+    mint: &AccountView,
+    system_program: &AccountView,
+    token_program: &AccountView,
+    signers: &[Signer],
+) -> ProgramResult {
+    invoke_create(IX_CREATE, payer, ata, wallet, mint, system_program, token_program, signers)
+}
+
+/// Create the ATA for `wallet` + `mint` if it does not exist yet.
+///
+/// Succeeds without changes when the ATA is already initialized for the
+/// same wallet and mint.
+#[inline(always)]
+pub fn create_idempotent(
+    payer: &AccountView,
+    ata: &AccountView,
+    wallet: &AccountView,
+    mint: &AccountView,
+    system_program: &AccountView,
+    token_program: &AccountView,
+) -> ProgramResult {
+    invoke_create(
+        IX_CREATE_IDEMPOTENT, payer, ata, wallet, mint, system_program, token_program, &[],
+    )
+}
+
+/// [`create_idempotent`] with PDA signer seeds for `payer`.
+#[allow(clippy::too_many_arguments)]
+#[inline(always)]
+pub fn create_idempotent_signed(
+    payer: &AccountView,
+    ata: &AccountView,
+    wallet: &AccountView,
+    mint: &AccountView,
+    system_program: &AccountView,
+    token_program: &AccountView,
+    signers: &[Signer],
+) -> ProgramResult {
+    invoke_create(
+        IX_CREATE_IDEMPOTENT, payer, ata, wallet, mint, system_program, token_program, signers,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+#[inline(always)]
+fn invoke_create(
+    tag: u8,
+    payer: &AccountView,
+    ata: &AccountView,
+    wallet: &AccountView,
+    mint: &AccountView,
+    system_program: &AccountView,
+    token_program: &AccountView,
+    signers: &[Signer],
+) -> ProgramResult {
+    let (expected, _) =
+        derive_ata_with_program(wallet.address(), mint.address(), token_program.address())?;
+    if *ata.address() != expected {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let ix = InstructionView {
+        program_id: &ASSOCIATED_TOKEN,
+        accounts: &[
+            InstructionAccount::writable_signer(payer.address()),
+            InstructionAccount::writable(ata.address()),
+            InstructionAccount::readonly(wallet.address()),
+            InstructionAccount::readonly(mint.address()),
+            InstructionAccount::readonly(system_program.address()),
+            InstructionAccount::readonly(token_program.address()),
+        ],
+        data: &[tag],
+    };
+    invoke_signed(&ix, &[payer, ata, wallet, mint, system_program, token_program], signers)
+}
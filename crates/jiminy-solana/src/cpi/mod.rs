@@ -11,8 +11,8 @@ pub mod safe;
 // ── Re-exports: safe wrappers ────────────────────────────────────────────────
 pub use safe::{
     safe_burn, safe_checked_transfer, safe_close_token_account, safe_create_account,
-    safe_create_account_signed, safe_mint_to, safe_mint_to_signed, safe_transfer_sol,
-    safe_transfer_tokens, safe_transfer_tokens_signed, transfer_lamports,
+    move_lamports, safe_create_account_signed, safe_mint_to, safe_mint_to_signed,
+    safe_transfer_sol, safe_transfer_tokens, safe_transfer_tokens_signed, transfer_lamports,
 };
 
 // ── Re-exports: reentrancy guard ─────────────────────────────────────────────
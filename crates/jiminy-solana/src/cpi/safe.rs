@@ -404,3 +404,33 @@ pub fn transfer_lamports(
     to.set_lamports(new_to);
     Ok(())
 }
+
+/// Move lamports out of an account this program owns, or explain why not.
+///
+/// The runtime only lets a program *debit* accounts it owns; crediting
+/// any writable account is fine. `set_lamports` on a system-owned `from`
+/// (a user wallet, an unfunded PDA) makes the whole transaction fail
+/// after the instruction returns, with an error that doesn't name the
+/// account. This checks the owner first:
+///
+/// - `from` owned by `program_id`: direct arithmetic via
+///   [`transfer_lamports`], no CPI.
+/// - `from` owned by anyone else: `IllegalOwner`. A system-owned payer
+///   must sign and go through `sys::transfer` / [`safe_transfer_sol`].
+///
+/// ```rust,ignore
+/// move_lamports(pool, user, program_id, payout)?;       // pool is our PDA
+/// sys::transfer(user, pool, deposit)?;                  // user wallet -> pool
+/// ```
+#[inline(always)]
+pub fn move_lamports(
+    from: &AccountView,
+    to: &AccountView,
+    program_id: &Address,
+    amount: u64,
+) -> ProgramResult {
+    if !from.owned_by(program_id) {
+        return Err(ProgramError::IllegalOwner);
+    }
+    transfer_lamports(from, to, amount)
+}
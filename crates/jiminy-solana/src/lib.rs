@@ -18,7 +18,7 @@
 //!
 //! | Module | |
 //! |---|---|
-//! | [`token`] | SPL Token account readers, mint readers, Token-2022 extension screening |
+//! | [`token`] | SPL Token account readers, mint readers, Token-2022 extension screening, program dispatch |
 //! | [`cpi`] | Safe CPI wrappers, reentrancy guards, return data readers |
 //! | [`crypto`] | Ed25519 precompile verification, Merkle proof verification |
 //! | [`authority`] | Two-step authority rotation (propose + accept) |
@@ -26,8 +26,12 @@
 //! | [`compute`] | Compute budget guards |
 //! | [`compose`] | Transaction composition guards (flash-loan detection) |
 //! | [`introspect`] | Raw transaction introspection |
+//! | [`metadata`] | Metaplex Token Metadata readers |
 //! | [`oracle`] | Pyth V2 price feed readers |
+//! | [`sys`] | System program CPI builders |
+//! | [`token_cpi`] | SPL Token / Token-2022 CPI builders |
 //! | [`twap`] | TWAP accumulators |
+//! | [`ata_cpi`] | Associated Token Account CPI builders *(feature: `programs`)* |
 //! | [`upgrade`] | Program upgrade authority verification *(feature: `programs`)* |
 //!
 //! Depends on [`jiminy_core`] for validation, math, and account IO.
@@ -43,10 +47,15 @@ pub mod balance;
 pub mod compute;
 pub mod compose;
 pub mod introspect;
+pub mod metadata;
 pub mod oracle;
 pub mod prelude;
+pub mod sys;
+pub mod token_cpi;
 pub mod twap;
 
+#[cfg(feature = "programs")]
+pub mod ata_cpi;
 #[cfg(feature = "programs")]
 pub mod upgrade;
 
@@ -0,0 +1,224 @@
+//! Zero-copy readers for Metaplex Token Metadata accounts.
+//!
+//! Reads fields straight out of the borsh-encoded `Metadata` account. No
+//! `mpl-token-metadata` dependency, no borsh, no alloc. Callers must check
+//! the account is owned by [`METADATA`](jiminy_core::programs::METADATA)
+//! (and usually that it is the metadata PDA for the mint) before trusting
+//! anything read here.
+//!
+//! Layout (MetadataV1):
+//! ```text
+//!  0       key               (u8 = 4)
+//!  1..33   update_authority  (Address)
+//! 33..65   mint              (Address)
+//! 65..     name              (u32 LE len + bytes, NUL-padded)
+//!          symbol            (u32 LE len + bytes, NUL-padded)
+//!          uri               (u32 LE len + bytes, NUL-padded)
+//!          seller_fee_bps    (u16 LE)
+//!          creators          (Option<Vec<Creator>>, 34 bytes per creator)
+//!          primary_sale      (bool)
+//!          is_mutable        (bool)
+//!          edition_nonce     (Option<u8>)
+//!          token_standard    (Option<u8>)
+//!          collection        (Option<{ verified: bool, key: Address }>)
+//! ```
+//!
+//! The strings are variable-length, so every reader walks the length
+//! prefixes from the start and bounds-checks each one.
+
+use hopper_runtime::{Address, ProgramError, ProgramResult};
+
+/// `Key::MetadataV1` tag in byte 0.
+pub const METADATA_KEY_V1: u8 = 4;
+
+const UPDATE_AUTHORITY_OFFSET: usize = 1;
+const MINT_OFFSET: usize = 33;
+const NAME_OFFSET: usize = 65;
+/// Address (32) + verified (1) + share (1).
+const CREATOR_LEN: usize = 34;
+
+/// The `collection` field of a metadata account.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MetadataCollection {
+    /// Whether the collection authority has verified membership.
+    pub verified: bool,
+    /// The collection NFT's mint.
+    pub key: Address,
+}
+
+/// Read the update authority (bytes 1..33).
+#[inline(always)]
+pub fn metadata_update_authority(data: &[u8]) -> Result<Address, ProgramError> {
+    read_address(data, UPDATE_AUTHORITY_OFFSET)
+}
+
+/// Read the mint this metadata describes (bytes 33..65).
+#[inline(always)]
+pub fn metadata_mint(data: &[u8]) -> Result<Address, ProgramError> {
+    read_address(data, MINT_OFFSET)
+}
+
+/// Read the `name` string with its NUL padding stripped.
+///
+/// ```rust,ignore
+/// let data = metadata.try_borrow()?;
+/// require!(metadata_name(&data)? == b"Jiminy #1", ProgramError::InvalidAccountData);
+/// ```
+#[inline(always)]
+pub fn metadata_name(data: &[u8]) -> Result<&[u8], ProgramError> {
+    check_key(data)?;
+    let (name, _) = read_string(data, NAME_OFFSET)?;
+    Ok(trim_nul(name))
+}
+
+/// Read the `symbol` string with its NUL padding stripped.
+#[inline(always)]
+pub fn metadata_symbol(data: &[u8]) -> Result<&[u8], ProgramError> {
+    check_key(data)?;
+    let (_, next) = read_string(data, NAME_OFFSET)?;
+    let (symbol, _) = read_string(data, next)?;
+    Ok(trim_nul(symbol))
+}
+
+/// Read the `uri` string with its NUL padding stripped.
+#[inline(always)]
+pub fn metadata_uri(data: &[u8]) -> Result<&[u8], ProgramError> {
+    check_key(data)?;
+    let (_, next) = read_string(data, NAME_OFFSET)?;
+    let (_, next) = read_string(data, next)?;
+    let (uri, _) = read_string(data, next)?;
+    Ok(trim_nul(uri))
+}
+
+/// Read the optional `collection` field.
+///
+/// Walks the variable-length fields before it. Accounts written before
+/// the field existed end early; a missing tail reads as `None`.
+#[inline(always)]
+pub fn metadata_collection(data: &[u8]) -> Result<Option<MetadataCollection>, ProgramError> {
+    check_key(data)?;
+    let (_, off) = read_string(data, NAME_OFFSET)?;
+    let (_, off) = read_string(data, off)?;
+    let (_, off) = read_string(data, off)?;
+    // seller_fee_basis_points
+    let mut off = off + 2;
+
+    // creators: Option<Vec<Creator>>
+    match data.get(off) {
+        None => return Ok(None),
+        Some(0) => off += 1,
+        Some(_) => {
+            if data.len() < off + 5 {
+                return Err(ProgramError::AccountDataTooSmall);
+            }
+            let count = u32::from_le_bytes([
+                data[off + 1], data[off + 2], data[off + 3], data[off + 4],
+            ]) as usize;
+            off = count
+                .checked_mul(CREATOR_LEN)
+                .and_then(|n| n.checked_add(off + 5))
+                .ok_or(ProgramError::AccountDataTooSmall)?;
+            if off > data.len() {
+                return Err(ProgramError::AccountDataTooSmall);
+            }
+        }
+    }
+
+    // primary_sale_happened, is_mutable
+    off += 2;
+    // edition_nonce, token_standard: Option<u8>
+    let mut i = 0;
+    while i < 2 {
+        match data.get(off) {
+            None => return Ok(None),
+            Some(0) => off += 1,
+            Some(_) => off += 2,
+        }
+        i += 1;
+    }
+
+    // collection: Option<Collection>
+    match data.get(off) {
+        None | Some(0) => Ok(None),
+        Some(_) => {
+            if data.len() < off + 34 {
+                return Err(ProgramError::AccountDataTooSmall);
+            }
+            let mut key = [0u8; 32];
+            key.copy_from_slice(&data[off + 2..off + 34]);
+            Ok(Some(MetadataCollection {
+                verified: data[off + 1] != 0,
+                key: Address::new_from_array(key),
+            }))
+        }
+    }
+}
+
+/// Verify the NFT belongs to the verified collection `collection_mint`.
+///
+/// Returns `InvalidArgument` if the metadata has no collection, names a
+/// different collection, or the membership is unverified. Check the
+/// metadata account's owner and PDA first.
+///
+/// ```rust,ignore
+/// check_metadata_pda(metadata, mint.address())?;
+/// check_verified_collection(&metadata.try_borrow()?, &COLLECTION_MINT)?;
+/// ```
+#[inline(always)]
+pub fn check_verified_collection(data: &[u8], collection_mint: &Address) -> ProgramResult {
+    match metadata_collection(data)? {
+        Some(c) if c.verified && c.key == *collection_mint => Ok(()),
+        _ => Err(ProgramError::InvalidArgument),
+    }
+}
+
+// ── Internal parsing ─────────────────────────────────────────────────────────
+
+/// Require the `MetadataV1` key byte.
+#[inline(always)]
+fn check_key(data: &[u8]) -> Result<(), ProgramError> {
+    match data.first() {
+        None => Err(ProgramError::AccountDataTooSmall),
+        Some(&METADATA_KEY_V1) => Ok(()),
+        Some(_) => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+#[inline(always)]
+fn read_address(data: &[u8], offset: usize) -> Result<Address, ProgramError> {
+    check_key(data)?;
+    if data.len() < offset + 32 {
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(&data[offset..offset + 32]);
+    Ok(Address::new_from_array(bytes))
+}
+
+/// Read a borsh `String` at `offset`. Returns the bytes and the offset
+/// just past them.
+#[inline(always)]
+fn read_string(data: &[u8], offset: usize) -> Result<(&[u8], usize), ProgramError> {
+    let start = offset.checked_add(4).ok_or(ProgramError::AccountDataTooSmall)?;
+    if start > data.len() {
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+    let len = u32::from_le_bytes([
+        data[offset], data[offset + 1], data[offset + 2], data[offset + 3],
+    ]) as usize;
+    let end = start.checked_add(len).ok_or(ProgramError::AccountDataTooSmall)?;
+    if end > data.len() {
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+    Ok((&data[start..end], end))
+}
+
+/// Strip the trailing NUL padding Metaplex writes into fixed-width strings.
+#[inline(always)]
+fn trim_nul(s: &[u8]) -> &[u8] {
+    let mut end = s.len();
+    while end > 0 && s[end - 1] == 0 {
+        end -= 1;
+    }
+    &s[..end]
+}
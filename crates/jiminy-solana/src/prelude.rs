@@ -6,20 +6,25 @@
 
 // ── Token account readers & assertions ───────────────────────────────────────
 pub use crate::token::{
-    check_no_close_authority, check_no_delegate, check_not_frozen,
+    assert_mint_matches, check_no_close_authority, check_no_delegate, check_not_frozen,
     check_token_account_frozen, check_token_account_initialized, check_token_account_mint,
     check_token_account_owner, check_token_balance_gte, check_token_program_match,
     token_account_amount, token_account_close_authority, token_account_delegate,
     token_account_delegated_amount, token_account_mint, token_account_owner,
-    token_account_state, TOKEN_ACCOUNT_LEN,
+    token_account_state, TokenAccount, TOKEN_ACCOUNT_LEN,
 };
 
 // ── Mint account readers & checks ────────────────────────────────────────────
 pub use crate::token::{
-    check_mint_authority, check_mint_owner, mint_authority, mint_decimals,
-    mint_freeze_authority, mint_is_initialized, mint_supply, MINT_LEN,
+    check_mint_authority, check_mint_decimals, check_mint_owner, check_stored_mint,
+    mint_authority, mint_decimals, mint_freeze_authority, mint_is_initialized, mint_supply,
+    MINT_LEN,
 };
 
+// ── Token program dispatch ───────────────────────────────────────────────────
+#[cfg(feature = "programs")]
+pub use crate::token::TokenContext;
+
 // ── Token-2022 extension reader ──────────────────────────────────────────────
 pub use crate::token::{
     calculate_transfer_fee, check_no_cpi_guard as check_no_token_cpi_guard,
@@ -28,8 +33,8 @@ pub use crate::token::{
     check_no_transfer_hook, check_not_non_transferable, check_safe_token_2022_mint,
     check_token_program_for_mint, find_extension, find_extension_account,
     find_extension_mint, find_extension_typed, has_extension, has_extension_account,
-    has_extension_mint, read_transfer_fee_config, ExtensionType, TransferFeeConfig,
-    TransferFeeEpochConfig,
+    has_extension_mint, read_transfer_fee_config, token2022_extension, ExtensionType,
+    TransferFeeConfig, TransferFeeEpochConfig,
 };
 
 // ── CPI guard (reentrancy protection) ────────────────────────────────────────
@@ -64,6 +69,13 @@ pub use crate::crypto::{sha256_leaf, verify_merkle_proof};
 // ── Authority handoff (two-step rotation) ────────────────────────────────────
 pub use crate::authority::{accept_authority, check_pending_authority, write_pending_authority};
 
+// ── Metaplex metadata readers ───────────────────────────────────────────────
+pub use crate::metadata::{
+    check_verified_collection, metadata_collection, metadata_mint, metadata_name,
+    metadata_symbol, metadata_update_authority, metadata_uri, MetadataCollection,
+    METADATA_KEY_V1,
+};
+
 // ── Pyth oracle readers ─────────────────────────────────────────────────────
 pub use crate::oracle::{
     read_pyth_price, read_pyth_ema, pyth_agg_pub_slot,
@@ -82,8 +94,8 @@ pub use crate::balance::{
 // ── Safe CPI wrappers ───────────────────────────────────────────────────────
 pub use crate::cpi::{
     safe_burn, safe_checked_transfer, safe_close_token_account, safe_create_account,
-    safe_create_account_signed, safe_mint_to, safe_mint_to_signed, safe_transfer_sol,
-    safe_transfer_tokens, safe_transfer_tokens_signed, transfer_lamports,
+    move_lamports, safe_create_account_signed, safe_mint_to, safe_mint_to_signed,
+    safe_transfer_sol, safe_transfer_tokens, safe_transfer_tokens_signed, transfer_lamports,
 };
 
 // ── CPI return data ─────────────────────────────────────────────────────────
@@ -95,6 +107,7 @@ pub use crate::cpi::{
 #[cfg(feature = "programs")]
 pub use crate::upgrade::{
     read_upgrade_authority, check_program_immutable, check_upgrade_authority,
+    check_upgrade_authority_signer,
 };
 
 // ── TWAP accumulators ────────────────────────────────────────────────────────
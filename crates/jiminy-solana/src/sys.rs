@@ -0,0 +1,212 @@
+//! System program CPI builders.
+//!
+//! `create_account` and `transfer` go through the hopper system
+//! builders; the rest encode their instruction directly into an
+//! [`InstructionView`]. Callers pass `AccountView`s and plain values; the
+//! program id, instruction index, and bincode layout are handled here.
+//!
+//! Every call has a `_signed` form taking PDA [`Signer`]s, for accounts
+//! the program signs for (see `signer_seeds!`).
+//!
+//! ```rust,ignore
+//! sys::create_account(payer, vault, program_id, rent_exempt_min(VAULT_LEN), VAULT_LEN as u64)?;
+//! ```
+
+use hopper_runtime::cpi::{invoke_signed, Signer};
+use hopper_runtime::instruction::{InstructionAccount, InstructionView};
+use hopper_runtime::system::instructions::{CreateAccount, Transfer};
+use hopper_runtime::{AccountView, Address, ProgramError, ProgramResult};
+use jiminy_core::check::SYSTEM_PROGRAM_ID;
+
+/// Longest seed the runtime accepts for `*_with_seed` addresses.
+pub const MAX_SEED_LEN: usize = 32;
+
+/// `Assign` instruction index.
+const IX_ASSIGN: u32 = 1;
+/// `CreateAccountWithSeed` instruction index.
+const IX_CREATE_ACCOUNT_WITH_SEED: u32 = 3;
+/// `Allocate` instruction index.
+const IX_ALLOCATE: u32 = 8;
+
+/// Create `new_account` funded by `payer`, with `space` bytes owned by `owner`.
+///
+/// Both accounts must sign. For a PDA `new_account` use
+/// [`create_account_signed`].
+#[inline(always)]
+pub fn create_account(
+    payer: &AccountView,
+    new_account: &AccountView,
+    owner: &Address,
+    lamports: u64,
+    space: u64,
+) -> ProgramResult {
+    create_account_signed(payer, new_account, owner, lamports, space, &[])
+}
+
+/// [`create_account`] with PDA signer seeds (typically for `new_account`).
+///
+/// ```rust,ignore
+/// signer_seeds!(vault_signer = [b"vault", authority.address()], bump);
+/// sys::create_account_signed(payer, vault, program_id, lamports, space, &[vault_signer])?;
+/// ```
+#[inline(always)]
+pub fn create_account_signed(
+    payer: &AccountView,
+    new_account: &AccountView,
+    owner: &Address,
+    lamports: u64,
+    space: u64,
+    signers: &[Signer],
+) -> ProgramResult {
+    CreateAccount {
+        from: payer,
+        to: new_account,
+        lamports,
+        space,
+        owner,
+    }
+    .invoke_signed(signers)
+}
+
+/// Create `new_account` at the address derived from `base`, `seed`, and
+/// `owner` (`sha256(base || seed || owner)`), funded by `from`.
+///
+/// `base` must sign; it may be the same account as `from`. Fails with
+/// `InvalidArgument` before invoking if `seed` is longer than
+/// [`MAX_SEED_LEN`] bytes.
+///
+/// ```rust,ignore
+/// sys::create_account_with_seed(payer, ticket, payer, "ticket-7", lamports, space, program_id)?;
+/// ```
+#[inline(always)]
+pub fn create_account_with_seed(
+    from: &AccountView,
+    new_account: &AccountView,
+    base: &AccountView,
+    seed: &str,
+    lamports: u64,
+    space: u64,
+    owner: &Address,
+) -> ProgramResult {
+    create_account_with_seed_signed(from, new_account, base, seed, lamports, space, owner, &[])
+}
+
+/// [`create_account_with_seed`] with PDA signer seeds (for `from` or `base`).
+#[allow(clippy::too_many_arguments)]
+#[inline(always)]
+pub fn create_account_with_seed_signed(
+    from: &AccountView,
+    new_account: &AccountView,
+    base: &AccountView,
+    seed: &str,
+    lamports: u64,
+    space: u64,
+    owner: &Address,
+    signers: &[Signer],
+) -> ProgramResult {
+    let seed = seed.as_bytes();
+    if seed.len() > MAX_SEED_LEN {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // [index u32][base 32][seed len u64][seed][lamports u64][space u64][owner 32]
+    let mut data = [0u8; 92 + MAX_SEED_LEN];
+    data[0..4].copy_from_slice(&IX_CREATE_ACCOUNT_WITH_SEED.to_le_bytes());
+    data[4..36].copy_from_slice(base.address().as_array());
+    data[36..44].copy_from_slice(&(seed.len() as u64).to_le_bytes());
+    let mut off = 44 + seed.len();
+    data[44..off].copy_from_slice(seed);
+    data[off..off + 8].copy_from_slice(&lamports.to_le_bytes());
+    data[off + 8..off + 16].copy_from_slice(&space.to_le_bytes());
+    data[off + 16..off + 48].copy_from_slice(owner.as_array());
+    off += 48;
+
+    // The base signer is only listed separately when it is not the funder.
+    if base.address() == from.address() {
+        let ix = InstructionView {
+            program_id: &SYSTEM_PROGRAM_ID,
+            accounts: &[
+                InstructionAccount::writable_signer(from.address()),
+                InstructionAccount::writable(new_account.address()),
+            ],
+            data: &data[..off],
+        };
+        invoke_signed(&ix, &[from, new_account], signers)
+    } else {
+        let ix = InstructionView {
+            program_id: &SYSTEM_PROGRAM_ID,
+            accounts: &[
+                InstructionAccount::writable_signer(from.address()),
+                InstructionAccount::writable(new_account.address()),
+                InstructionAccount::readonly_signer(base.address()),
+            ],
+            data: &data[..off],
+        };
+        invoke_signed(&ix, &[from, new_account, base], signers)
+    }
+}
+
+/// Transfer `lamports` from `from` (a system-owned signer) to `to`.
+#[inline(always)]
+pub fn transfer(from: &AccountView, to: &AccountView, lamports: u64) -> ProgramResult {
+    transfer_signed(from, to, lamports, &[])
+}
+
+/// [`transfer`] with PDA signer seeds for `from`.
+#[inline(always)]
+pub fn transfer_signed(
+    from: &AccountView,
+    to: &AccountView,
+    lamports: u64,
+    signers: &[Signer],
+) -> ProgramResult {
+    Transfer { from, to, lamports }.invoke_signed(signers)
+}
+
+/// Allocate `space` bytes of data for a system-owned `account`.
+#[inline(always)]
+pub fn allocate(account: &AccountView, space: u64) -> ProgramResult {
+    allocate_signed(account, space, &[])
+}
+
+/// [`allocate`] with PDA signer seeds for `account`.
+#[inline(always)]
+pub fn allocate_signed(account: &AccountView, space: u64, signers: &[Signer]) -> ProgramResult {
+    // [index u32][space u64]
+    let mut data = [0u8; 12];
+    data[0..4].copy_from_slice(&IX_ALLOCATE.to_le_bytes());
+    data[4..12].copy_from_slice(&space.to_le_bytes());
+
+    let ix = InstructionView {
+        program_id: &SYSTEM_PROGRAM_ID,
+        accounts: &[InstructionAccount::writable_signer(account.address())],
+        data: &data,
+    };
+    invoke_signed(&ix, &[account], signers)
+}
+
+/// Assign a system-owned `account` to `owner`.
+///
+/// With [`allocate`] and a [`transfer`] for rent, this is the
+/// create-account path for an address that already holds lamports
+/// (where `CreateAccount` would fail).
+#[inline(always)]
+pub fn assign(account: &AccountView, owner: &Address) -> ProgramResult {
+    assign_signed(account, owner, &[])
+}
+
+/// [`assign`] with PDA signer seeds for `account`.
+#[inline(always)]
+pub fn assign_signed(account: &AccountView, owner: &Address, signers: &[Signer]) -> ProgramResult {
+    // [index u32][owner 32]
+    let mut data = [0u8; 36];
+    data[0..4].copy_from_slice(&IX_ASSIGN.to_le_bytes());
+    data[4..36].copy_from_slice(owner.as_array());
+
+    let ix = InstructionView {
+        program_id: &SYSTEM_PROGRAM_ID,
+        accounts: &[InstructionAccount::writable_signer(account.address())],
+        data: &data,
+    };
+    invoke_signed(&ix, &[account], signers)
+}
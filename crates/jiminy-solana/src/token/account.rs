@@ -19,7 +19,7 @@
 //! 133..165 close_authority key (Address, if present)
 //! ```
 
-use hopper_runtime::{ProgramError, AccountView, Address, ProgramResult};
+use hopper_runtime::{ProgramError, AccountView, Address, ProgramResult, Ref};
 
 /// Minimum size of an SPL Token account.
 pub const TOKEN_ACCOUNT_LEN: usize = 165;
@@ -194,6 +194,150 @@ pub fn token_account_delegated_amount(account: &AccountView) -> Result<u64, Prog
     Ok(val)
 }
 
+// ── Whole-account view ───────────────────────────────────────────────────────
+
+/// Byte-for-byte image of the 165-byte token account. Every field is a
+/// byte array, so the struct has alignment 1 and no padding.
+#[repr(C)]
+struct RawTokenAccount {
+    mint: Address,
+    owner: Address,
+    amount: [u8; 8],
+    delegate_tag: [u8; 4],
+    delegate: Address,
+    state: u8,
+    is_native_tag: [u8; 4],
+    native_amount: [u8; 8],
+    delegated_amount: [u8; 8],
+    close_authority_tag: [u8; 4],
+    close_authority: Address,
+}
+
+const _: () = assert!(core::mem::size_of::<RawTokenAccount>() == TOKEN_ACCOUNT_LEN);
+const _: () = assert!(core::mem::align_of::<RawTokenAccount>() == 1);
+
+/// Borrowed view over a whole token account.
+///
+/// Borrows the data and checks the length once; each accessor is then a
+/// plain field read, where each `token_account_*` reader borrows and
+/// checks again. The `TokenAccount vs field readers` row of the CU bench
+/// compares the two. Addresses come back by reference, tied to the held
+/// borrow.
+///
+/// Does not check the owner. Verify the account belongs to the token
+/// program first.
+///
+/// ```rust,ignore
+/// let vault = TokenAccount::load(vault_ata)?;
+/// require_keys_eq!(vault.mint(), &expected_mint, ProgramError::InvalidArgument);
+/// require_keys_eq!(vault.owner(), vault_pda.address(), ProgramError::InvalidArgument);
+/// require_gte!(vault.amount(), amount, ProgramError::InsufficientFunds);
+/// ```
+pub struct TokenAccount<'a> {
+    data: Ref<'a, [u8]>,
+}
+
+impl<'a> TokenAccount<'a> {
+    /// Borrow `account`'s data as a token account.
+    #[inline(always)]
+    pub fn load(account: &'a AccountView) -> Result<Self, ProgramError> {
+        Self::new(account.try_borrow()?)
+    }
+
+    /// Wrap an existing borrow. Fails with `AccountDataTooSmall` under
+    /// [`TOKEN_ACCOUNT_LEN`] bytes. Token-2022 extension bytes past the
+    /// base layout are ignored.
+    #[inline(always)]
+    pub fn new(data: Ref<'a, [u8]>) -> Result<Self, ProgramError> {
+        if data.len() < TOKEN_ACCOUNT_LEN {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+        Ok(Self { data })
+    }
+
+    #[inline(always)]
+    fn raw(&self) -> &RawTokenAccount {
+        // SAFETY: `new` checked the length, and RawTokenAccount is
+        // alignment-1 with no invalid bit patterns.
+        unsafe { &*(self.data.as_ptr() as *const RawTokenAccount) }
+    }
+
+    /// The mint (bytes 0..32).
+    #[inline(always)]
+    pub fn mint(&self) -> &Address {
+        &self.raw().mint
+    }
+
+    /// The owner (bytes 32..64).
+    #[inline(always)]
+    pub fn owner(&self) -> &Address {
+        &self.raw().owner
+    }
+
+    /// The token balance (bytes 64..72).
+    #[inline(always)]
+    pub fn amount(&self) -> u64 {
+        u64::from_le_bytes(self.raw().amount)
+    }
+
+    /// The delegate, if one is set.
+    #[inline(always)]
+    pub fn delegate(&self) -> Option<&Address> {
+        let raw = self.raw();
+        if raw.delegate_tag == [0; 4] {
+            None
+        } else {
+            Some(&raw.delegate)
+        }
+    }
+
+    /// The raw state byte: 0 = uninitialized, 1 = initialized, 2 = frozen.
+    #[inline(always)]
+    pub fn state(&self) -> u8 {
+        self.raw().state
+    }
+
+    /// Whether the state is `Initialized` or `Frozen`.
+    #[inline(always)]
+    pub fn is_initialized(&self) -> bool {
+        self.raw().state != 0
+    }
+
+    /// Whether the account is frozen.
+    #[inline(always)]
+    pub fn is_frozen(&self) -> bool {
+        self.raw().state == 2
+    }
+
+    /// The rent-exempt reserve of a wrapped-SOL account, `None` otherwise.
+    #[inline(always)]
+    pub fn native_amount(&self) -> Option<u64> {
+        let raw = self.raw();
+        if raw.is_native_tag == [0; 4] {
+            None
+        } else {
+            Some(u64::from_le_bytes(raw.native_amount))
+        }
+    }
+
+    /// Tokens the delegate may transfer (bytes 121..129).
+    #[inline(always)]
+    pub fn delegated_amount(&self) -> u64 {
+        u64::from_le_bytes(self.raw().delegated_amount)
+    }
+
+    /// The close authority, if one is set.
+    #[inline(always)]
+    pub fn close_authority(&self) -> Option<&Address> {
+        let raw = self.raw();
+        if raw.close_authority_tag == [0; 4] {
+            None
+        } else {
+            Some(&raw.close_authority)
+        }
+    }
+}
+
 // ── Token Account Assertions ─────────────────────────────────────────────────
 //
 // Composable single-line checks that combine a reader + comparison.
@@ -220,15 +364,39 @@ pub fn check_token_account_mint(
     Ok(())
 }
 
+/// Verify a token account's mint is the mint account actually passed.
+///
+/// Reads the stored mint (bytes 0..32) and compares it with
+/// `mint_account.address()`. Returns `InvalidArgument` on mismatch.
+/// Pair with [`check_mint_owner`](crate::token::check_mint_owner) so the
+/// mint account itself is trusted.
+///
+/// ```rust,ignore
+/// assert_mint_matches(user_token, mint)?;
+/// let decimals = mint_decimals(mint)?;
+/// ```
+#[inline(always)]
+pub fn assert_mint_matches(
+    token_account: &AccountView,
+    mint_account: &AccountView,
+) -> ProgramResult {
+    check_token_account_mint(token_account, mint_account.address())
+}
+
 /// Verify a token account's owner matches the expected authority.
 ///
 /// Equivalent to Anchor's `token::authority = expected_authority`.
 /// Use this to confirm the token account belongs to the correct wallet
 /// or PDA before transferring tokens out of it.
 ///
+/// This is the SPL owner stored in the data (bytes 32..64), not the
+/// program that owns the account. For the latter (SPL Token vs
+/// Token-2022) use [`check_token_program_match`] or `check_owner`.
+///
 /// ```rust,ignore
 /// check_token_account_owner(user_token, user.address())?;
 /// ```
+#[doc(alias = "assert_token_owner")]
 #[inline(always)]
 pub fn check_token_account_owner(
     account: &AccountView,
@@ -378,6 +546,7 @@ pub fn check_token_program_match(
 /// ```rust,ignore
 /// check_token_balance_gte(source_token, transfer_amount)?;
 /// ```
+#[doc(alias = "check_token_amount_gte")]
 #[inline(always)]
 pub fn check_token_balance_gte(account: &AccountView, min_amount: u64) -> ProgramResult {
     let amount = token_account_amount(account)?;
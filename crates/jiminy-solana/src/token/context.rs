@@ -0,0 +1,179 @@
+//! Token program dispatch for SPL Token and Token-2022.
+//!
+//! [`TokenContext`] wraps the token program account passed to the
+//! instruction, validates that it is one of the two token programs, and
+//! issues checked transfer/burn/mint CPIs against whichever one it is.
+//! Callers never have to branch on the program id themselves.
+//!
+//! Only the `*Checked` instruction variants are used. Token-2022 rejects
+//! unchecked transfers for mints with fee or hook extensions, and both
+//! programs accept the checked forms.
+//!
+//! ```rust,ignore
+//! let token = TokenContext::new(token_program)?;
+//! token.transfer(source, mint, dest, owner, amount)?;
+//! ```
+
+use hopper_runtime::cpi::{invoke_signed, Signer};
+use hopper_runtime::instruction::{InstructionAccount, InstructionView};
+use hopper_runtime::{ProgramError, AccountView, Address, ProgramResult};
+use jiminy_core::programs;
+
+use super::mint::mint_decimals;
+use crate::token_cpi::{self, checked_data};
+
+/// `MintToChecked` instruction tag.
+const IX_MINT_TO_CHECKED: u8 = 14;
+/// `BurnChecked` instruction tag.
+const IX_BURN_CHECKED: u8 = 15;
+
+/// A validated token program account (SPL Token or Token-2022).
+///
+/// All CPIs are addressed to the program id of the wrapped account, so
+/// the same handler code serves both programs.
+#[derive(Clone, Copy)]
+pub struct TokenContext<'a> {
+    program: &'a AccountView,
+}
+
+impl<'a> TokenContext<'a> {
+    /// Wrap `token_program`, failing with `IncorrectProgramId` unless it
+    /// is SPL Token or Token-2022.
+    #[inline(always)]
+    pub fn new(token_program: &'a AccountView) -> Result<Self, ProgramError> {
+        if !programs::is_token_program(token_program.address()) {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        Ok(Self { program: token_program })
+    }
+
+    /// The wrapped token program account.
+    #[inline(always)]
+    pub fn program(&self) -> &'a AccountView {
+        self.program
+    }
+
+    /// The wrapped token program id.
+    #[inline(always)]
+    pub fn program_id(&self) -> &'a Address {
+        self.program.address()
+    }
+
+    /// Whether the wrapped program is Token-2022.
+    #[inline(always)]
+    pub fn is_token_2022(&self) -> bool {
+        *self.program.address() == programs::TOKEN_2022
+    }
+
+    /// `TransferChecked` from `from` to `to`, signed by `authority`.
+    ///
+    /// Decimals are read from `mint`.
+    #[inline(always)]
+    pub fn transfer(
+        &self,
+        from: &AccountView,
+        mint: &AccountView,
+        to: &AccountView,
+        authority: &AccountView,
+        amount: u64,
+    ) -> ProgramResult {
+        self.transfer_signed(from, mint, to, authority, amount, &[])
+    }
+
+    /// [`transfer`](Self::transfer) with PDA signer seeds for `authority`.
+    #[inline(always)]
+    pub fn transfer_signed(
+        &self,
+        from: &AccountView,
+        mint: &AccountView,
+        to: &AccountView,
+        authority: &AccountView,
+        amount: u64,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        token_cpi::transfer_checked_signed(
+            self.program,
+            from,
+            mint,
+            to,
+            authority,
+            amount,
+            mint_decimals(mint)?,
+            signers,
+        )
+    }
+
+    /// `BurnChecked` from `account`, signed by `authority`.
+    ///
+    /// Decimals are read from `mint`.
+    #[inline(always)]
+    pub fn burn(
+        &self,
+        account: &AccountView,
+        mint: &AccountView,
+        authority: &AccountView,
+        amount: u64,
+    ) -> ProgramResult {
+        self.burn_signed(account, mint, authority, amount, &[])
+    }
+
+    /// [`burn`](Self::burn) with PDA signer seeds for `authority`.
+    #[inline(always)]
+    pub fn burn_signed(
+        &self,
+        account: &AccountView,
+        mint: &AccountView,
+        authority: &AccountView,
+        amount: u64,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        let data = checked_data(IX_BURN_CHECKED, amount, mint_decimals(mint)?);
+        let ix = InstructionView {
+            program_id: self.program.address(),
+            accounts: &[
+                InstructionAccount::writable(account.address()),
+                InstructionAccount::writable(mint.address()),
+                InstructionAccount::readonly_signer(authority.address()),
+            ],
+            data: &data,
+        };
+        invoke_signed(&ix, &[account, mint, authority], signers)
+    }
+
+    /// `MintToChecked` into `account`, signed by the mint `authority`.
+    ///
+    /// Decimals are read from `mint`.
+    #[inline(always)]
+    pub fn mint_to(
+        &self,
+        mint: &AccountView,
+        account: &AccountView,
+        authority: &AccountView,
+        amount: u64,
+    ) -> ProgramResult {
+        self.mint_to_signed(mint, account, authority, amount, &[])
+    }
+
+    /// [`mint_to`](Self::mint_to) with PDA signer seeds for `authority`.
+    #[inline(always)]
+    pub fn mint_to_signed(
+        &self,
+        mint: &AccountView,
+        account: &AccountView,
+        authority: &AccountView,
+        amount: u64,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        let data = checked_data(IX_MINT_TO_CHECKED, amount, mint_decimals(mint)?);
+        let ix = InstructionView {
+            program_id: self.program.address(),
+            accounts: &[
+                InstructionAccount::writable(mint.address()),
+                InstructionAccount::writable(account.address()),
+                InstructionAccount::readonly_signer(authority.address()),
+            ],
+            data: &data,
+        };
+        invoke_signed(&ix, &[mint, account, authority], signers)
+    }
+}
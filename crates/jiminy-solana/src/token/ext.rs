@@ -30,7 +30,7 @@
 //! that change transfer semantics (transfer fees, hooks, non-transferable,
 //! permanent delegate). Ignoring these is a critical vulnerability.
 
-use hopper_runtime::{ProgramError, AccountView, ProgramResult};
+use hopper_runtime::{ProgramError, AccountView, ProgramResult, Ref};
 
 /// Base mint length before extensions.
 pub const BASE_MINT_LEN: usize = 82;
@@ -276,6 +276,48 @@ pub fn find_extension_account(
     find_extension_typed(data, ACCOUNT_TYPE_ACCOUNT, ext_type)
 }
 
+/// Borrow `account` and find an extension, whichever kind it is.
+///
+/// The base layout is read as-is: 82-byte mints and 165-byte accounts
+/// (classic SPL or non-extended Token-2022) return `Ok(None)`. Longer
+/// buffers must carry [`ACCOUNT_TYPE_MINT`] or [`ACCOUNT_TYPE_ACCOUNT`] at
+/// byte 165, followed by the TLV stream:
+///
+/// ```text
+///   [type: u16 LE][length: u16 LE][value: length bytes] ...
+/// ```
+///
+/// Any other length or type byte fails with `InvalidAccountData`. The
+/// returned slice keeps the account borrowed; drop it before a CPI that
+/// writes the account. When you know which kind to expect, use
+/// [`find_extension_mint`]/[`find_extension_account`] on the borrow
+/// instead.
+///
+/// ```rust,ignore
+/// if let Some(ptr) = token2022_extension(mint, ExtensionType::MetadataPointer)? {
+///     let metadata_address = &ptr[32..64];
+/// }
+/// ```
+#[inline]
+pub fn token2022_extension(
+    account: &AccountView,
+    ext_type: ExtensionType,
+) -> Result<Option<Ref<'_, [u8]>>, ProgramError> {
+    let data = account.try_borrow()?;
+    let len = data.len();
+    if len == BASE_MINT_LEN || len == BASE_ACCOUNT_LEN {
+        return Ok(None);
+    }
+    if len < TLV_START {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let kind = data[ACCOUNT_TYPE_OFFSET];
+    if kind != ACCOUNT_TYPE_MINT && kind != ACCOUNT_TYPE_ACCOUNT {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(Ref::filter_map(data, |d| find_extension(d, ext_type)).ok())
+}
+
 /// Check if a specific extension exists in the account data.
 ///
 /// Returns `true` if the extension type is found in the TLV entries.
@@ -176,3 +176,42 @@ pub fn check_mint_authority(account: &AccountView, expected: &Address) -> Progra
         _ => Err(ProgramError::InvalidArgument),
     }
 }
+
+/// Verify the mint's decimals (byte 44) equal `expected`.
+///
+/// For programs whose math assumes a fixed precision. Returns
+/// `InvalidAccountData` on mismatch.
+///
+/// ```rust,ignore
+/// check_mint_decimals(usdc_mint, 6)?;
+/// ```
+#[inline(always)]
+pub fn check_mint_decimals(account: &AccountView, expected: u8) -> ProgramResult {
+    if mint_decimals(account)? != expected {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(())
+}
+
+/// Verify a mint address stored in program state matches `mint_account`.
+///
+/// Reads the 32-byte address at `offset` in `data` (typically a config
+/// or pool account) and compares it to the passed mint. Returns
+/// `AccountDataTooSmall` if the address does not fit, `InvalidArgument`
+/// on mismatch.
+///
+/// ```rust,ignore
+/// let data = pool.try_borrow()?;
+/// check_stored_mint(&data, POOL_MINT_OFFSET, mint)?;
+/// ```
+#[inline(always)]
+pub fn check_stored_mint(data: &[u8], offset: usize, mint_account: &AccountView) -> ProgramResult {
+    let end = offset.checked_add(32).ok_or(ProgramError::AccountDataTooSmall)?;
+    if end > data.len() {
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+    if data[offset..end] != *mint_account.address().as_array() {
+        return Err(ProgramError::InvalidArgument);
+    }
+    Ok(())
+}
@@ -0,0 +1,244 @@
+//! SPL Token CPI builders.
+//!
+//! Encode token instructions directly into an [`InstructionView`] and
+//! invoke them against the `token_program` account the caller passes.
+//! The instruction is addressed to that account's key, so the same call
+//! works for SPL Token and Token-2022. Validate the program first (see
+//! `assert_token_program` or [`TokenContext`](crate::token::TokenContext),
+//! which also picks the checked variants for you).
+//!
+//! Pass the program that owns the token accounts and mint: SPL Token for
+//! classic mints, Token-2022 for 2022 mints. Token-2022 keeps the original
+//! instruction tags, so the encoding is the same for both, but a CPI to
+//! the wrong program fails with `IncorrectProgramId` from the runtime.
+//!
+//! Every call has a `_signed` form taking PDA [`Signer`]s for vault-style
+//! authorities (see `signer_seeds!`).
+//!
+//! ```rust,ignore
+//! token_cpi::transfer_checked(token_program, vault, mint, user_ata, vault_pda, amount, 6)?;
+//! ```
+
+use hopper_runtime::cpi::{invoke_signed, Signer};
+use hopper_runtime::instruction::{InstructionAccount, InstructionView};
+use hopper_runtime::{AccountView, ProgramResult};
+
+/// `Transfer` instruction tag.
+const IX_TRANSFER: u8 = 3;
+/// `MintTo` instruction tag.
+const IX_MINT_TO: u8 = 7;
+/// `Burn` instruction tag.
+const IX_BURN: u8 = 8;
+/// `CloseAccount` instruction tag.
+const IX_CLOSE_ACCOUNT: u8 = 9;
+/// `TransferChecked` instruction tag.
+const IX_TRANSFER_CHECKED: u8 = 12;
+
+/// `Transfer` `amount` from `from` to `to`, signed by `authority`.
+///
+/// Token-2022 rejects unchecked transfers for mints with transfer-fee or
+/// hook extensions; prefer [`transfer_checked`] when the mint is unknown.
+#[inline(always)]
+pub fn transfer(
+    token_program: &AccountView,
+    from: &AccountView,
+    to: &AccountView,
+    authority: &AccountView,
+    amount: u64,
+) -> ProgramResult {
+    transfer_signed(token_program, from, to, authority, amount, &[])
+}
+
+/// [`transfer`] with PDA signer seeds for `authority`.
+#[inline(always)]
+pub fn transfer_signed(
+    token_program: &AccountView,
+    from: &AccountView,
+    to: &AccountView,
+    authority: &AccountView,
+    amount: u64,
+    signers: &[Signer],
+) -> ProgramResult {
+    let data = amount_data(IX_TRANSFER, amount);
+    let ix = InstructionView {
+        program_id: token_program.address(),
+        accounts: &[
+            InstructionAccount::writable(from.address()),
+            InstructionAccount::writable(to.address()),
+            InstructionAccount::readonly_signer(authority.address()),
+        ],
+        data: &data,
+    };
+    invoke_signed(&ix, &[from, to, authority], signers)
+}
+
+/// `TransferChecked` `amount` from `from` to `to`, signed by `authority`.
+///
+/// The token program rejects the transfer unless `mint` matches both
+/// accounts and `decimals` matches the mint.
+#[inline(always)]
+pub fn transfer_checked(
+    token_program: &AccountView,
+    from: &AccountView,
+    mint: &AccountView,
+    to: &AccountView,
+    authority: &AccountView,
+    amount: u64,
+    decimals: u8,
+) -> ProgramResult {
+    transfer_checked_signed(token_program, from, mint, to, authority, amount, decimals, &[])
+}
+
+/// [`transfer_checked`] with PDA signer seeds for `authority`.
+#[allow(clippy::too_many_arguments)]
+#[inline(always)]
+pub fn transfer_checked_signed(
+    token_program: &AccountView,
+    from: &AccountView,
+    mint: &AccountView,
+    to: &AccountView,
+    authority: &AccountView,
+    amount: u64,
+    decimals: u8,
+    signers: &[Signer],
+) -> ProgramResult {
+    let data = checked_data(IX_TRANSFER_CHECKED, amount, decimals);
+    let ix = InstructionView {
+        program_id: token_program.address(),
+        accounts: &[
+            InstructionAccount::writable(from.address()),
+            InstructionAccount::readonly(mint.address()),
+            InstructionAccount::writable(to.address()),
+            InstructionAccount::readonly_signer(authority.address()),
+        ],
+        data: &data,
+    };
+    invoke_signed(&ix, &[from, mint, to, authority], signers)
+}
+
+/// `MintTo` `amount` new tokens of `mint` into `to`, signed by the mint `authority`.
+#[inline(always)]
+pub fn mint_to(
+    token_program: &AccountView,
+    mint: &AccountView,
+    to: &AccountView,
+    authority: &AccountView,
+    amount: u64,
+) -> ProgramResult {
+    mint_to_signed(token_program, mint, to, authority, amount, &[])
+}
+
+/// [`mint_to`] with PDA signer seeds for the mint `authority`.
+#[inline(always)]
+pub fn mint_to_signed(
+    token_program: &AccountView,
+    mint: &AccountView,
+    to: &AccountView,
+    authority: &AccountView,
+    amount: u64,
+    signers: &[Signer],
+) -> ProgramResult {
+    let data = amount_data(IX_MINT_TO, amount);
+    let ix = InstructionView {
+        program_id: token_program.address(),
+        accounts: &[
+            InstructionAccount::writable(mint.address()),
+            InstructionAccount::writable(to.address()),
+            InstructionAccount::readonly_signer(authority.address()),
+        ],
+        data: &data,
+    };
+    invoke_signed(&ix, &[mint, to, authority], signers)
+}
+
+/// `Burn` `amount` tokens from `account`, signed by its owner or delegate.
+#[inline(always)]
+pub fn burn(
+    token_program: &AccountView,
+    account: &AccountView,
+    mint: &AccountView,
+    authority: &AccountView,
+    amount: u64,
+) -> ProgramResult {
+    burn_signed(token_program, account, mint, authority, amount, &[])
+}
+
+/// [`burn`] with PDA signer seeds for `authority`.
+#[inline(always)]
+pub fn burn_signed(
+    token_program: &AccountView,
+    account: &AccountView,
+    mint: &AccountView,
+    authority: &AccountView,
+    amount: u64,
+    signers: &[Signer],
+) -> ProgramResult {
+    let data = amount_data(IX_BURN, amount);
+    let ix = InstructionView {
+        program_id: token_program.address(),
+        accounts: &[
+            InstructionAccount::writable(account.address()),
+            InstructionAccount::writable(mint.address()),
+            InstructionAccount::readonly_signer(authority.address()),
+        ],
+        data: &data,
+    };
+    invoke_signed(&ix, &[account, mint, authority], signers)
+}
+
+/// `CloseAccount`: send `account`'s lamports to `destination` and close it.
+///
+/// The token balance must be zero (native SOL accounts excepted), and
+/// `authority` must be the owner or close authority.
+#[inline(always)]
+pub fn close_account(
+    token_program: &AccountView,
+    account: &AccountView,
+    destination: &AccountView,
+    authority: &AccountView,
+) -> ProgramResult {
+    close_account_signed(token_program, account, destination, authority, &[])
+}
+
+/// [`close_account`] with PDA signer seeds for `authority`.
+#[inline(always)]
+pub fn close_account_signed(
+    token_program: &AccountView,
+    account: &AccountView,
+    destination: &AccountView,
+    authority: &AccountView,
+    signers: &[Signer],
+) -> ProgramResult {
+    let ix = InstructionView {
+        program_id: token_program.address(),
+        accounts: &[
+            InstructionAccount::writable(account.address()),
+            InstructionAccount::writable(destination.address()),
+            InstructionAccount::readonly_signer(authority.address()),
+        ],
+        data: &[IX_CLOSE_ACCOUNT],
+    };
+    invoke_signed(&ix, &[account, destination, authority], signers)
+}
+
+// ── Instruction data ─────────────────────────────────────────────────────────
+
+/// Encode `[tag, amount (u64 LE)]`.
+#[inline(always)]
+fn amount_data(tag: u8, amount: u64) -> [u8; 9] {
+    let mut data = [0u8; 9];
+    data[0] = tag;
+    data[1..9].copy_from_slice(&amount.to_le_bytes());
+    data
+}
+
+/// Encode `[tag, amount (u64 LE), decimals]`, the shared layout of the
+/// `*Checked` token instructions.
+#[inline(always)]
+pub(crate) fn checked_data(tag: u8, amount: u64, decimals: u8) -> [u8; 10] {
+    let mut data = [0u8; 10];
+    data[0] = tag;
+    data[1..9].copy_from_slice(&amount.to_le_bytes());
+    data[9] = decimals;
+    data
+}
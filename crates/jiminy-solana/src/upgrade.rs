@@ -105,3 +105,39 @@ pub fn check_upgrade_authority(
         _ => Err(ProgramError::InvalidArgument),
     }
 }
+
+/// Verify `authority` signed and is the upgrade authority of `program_id`.
+///
+/// Gates admin instructions on "only whoever can upgrade this program"
+/// without a config account. `program_data` must be the ProgramData PDA
+/// of `program_id`: this re-derives it (a `find_program_address`, so
+/// budget the CU) because any other upgradeable program's data account
+/// would otherwise pass with its own authority. Fails with
+/// `MissingRequiredSignature` if `authority` did not sign, `InvalidSeeds`
+/// for the wrong ProgramData account, and `InvalidArgument` if the
+/// program is immutable or upgraded by someone else.
+///
+/// ```rust,ignore
+/// let admin = accs.next_signer()?;
+/// let program_data = accs.next()?;
+/// check_upgrade_authority_signer(program_data, program_id, admin)?;
+/// ```
+#[cfg(feature = "programs")]
+#[inline(always)]
+pub fn check_upgrade_authority_signer(
+    program_data: &AccountView,
+    program_id: &Address,
+    authority: &AccountView,
+) -> ProgramResult {
+    if !authority.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let (expected, _) = jiminy_core::check::pda::find_program_address(
+        &[program_id.as_array()],
+        &jiminy_core::programs::BPF_LOADER,
+    );
+    if *program_data.address() != expected {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    check_upgrade_authority(program_data, authority.address())
+}
@@ -0,0 +1,153 @@
+//! Tests for the Metaplex metadata readers.
+
+use jiminy_solana::metadata::*;
+use jiminy_solana::{Address, ProgramError};
+
+// ── Fixtures ─────────────────────────────────────────────────────────────────
+
+fn push_str(buf: &mut Vec<u8>, s: &[u8], width: usize) {
+    buf.extend_from_slice(&(width as u32).to_le_bytes());
+    buf.extend_from_slice(s);
+    buf.resize(buf.len() + width - s.len(), 0);
+}
+
+/// A MetadataV1 account with the fixed Metaplex string widths.
+fn metadata(name: &[u8], symbol: &[u8], uri: &[u8]) -> Vec<u8> {
+    let mut buf = vec![METADATA_KEY_V1];
+    buf.extend_from_slice(&[0xAA; 32]);
+    buf.extend_from_slice(&[0xBB; 32]);
+    push_str(&mut buf, name, 32);
+    push_str(&mut buf, symbol, 10);
+    push_str(&mut buf, uri, 200);
+    buf
+}
+
+/// Append the tail fields after `uri`, with an optional collection.
+fn with_tail(mut buf: Vec<u8>, creators: u32, collection: Option<(bool, [u8; 32])>) -> Vec<u8> {
+    buf.extend_from_slice(&500u16.to_le_bytes());
+    if creators == 0 {
+        buf.push(0);
+    } else {
+        buf.push(1);
+        buf.extend_from_slice(&creators.to_le_bytes());
+        for i in 0..creators {
+            buf.extend_from_slice(&[i as u8; 32]);
+            buf.extend_from_slice(&[1, 100]);
+        }
+    }
+    buf.extend_from_slice(&[1, 1]); // primary_sale_happened, is_mutable
+    buf.extend_from_slice(&[1, 254]); // edition_nonce = Some(254)
+    buf.push(0); // token_standard = None
+    match collection {
+        None => buf.push(0),
+        Some((verified, key)) => {
+            buf.extend_from_slice(&[1, verified as u8]);
+            buf.extend_from_slice(&key);
+        }
+    }
+    buf.extend_from_slice(&[0; 16]); // uses, etc.
+    buf
+}
+
+const COLLECTION: [u8; 32] = [0xC0; 32];
+
+// ── Readers ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn reads_fixed_fields() {
+    let data = metadata(b"Jiminy #1", b"JMNY", b"https://example.com/1.json");
+    assert_eq!(metadata_update_authority(&data), Ok(Address::new_from_array([0xAA; 32])));
+    assert_eq!(metadata_mint(&data), Ok(Address::new_from_array([0xBB; 32])));
+}
+
+#[test]
+fn reads_strings_without_padding() {
+    let data = metadata(b"Jiminy #1", b"JMNY", b"https://example.com/1.json");
+    assert_eq!(metadata_name(&data), Ok(&b"Jiminy #1"[..]));
+    assert_eq!(metadata_symbol(&data), Ok(&b"JMNY"[..]));
+    assert_eq!(metadata_uri(&data), Ok(&b"https://example.com/1.json"[..]));
+}
+
+#[test]
+fn reads_unpadded_strings() {
+    let mut data = vec![METADATA_KEY_V1];
+    data.extend_from_slice(&[0u8; 64]);
+    for s in [&b"n"[..], b"", b"u"] {
+        data.extend_from_slice(&(s.len() as u32).to_le_bytes());
+        data.extend_from_slice(s);
+    }
+    assert_eq!(metadata_name(&data), Ok(&b"n"[..]));
+    assert_eq!(metadata_symbol(&data), Ok(&b""[..]));
+    assert_eq!(metadata_uri(&data), Ok(&b"u"[..]));
+}
+
+#[test]
+fn rejects_wrong_key() {
+    let mut data = metadata(b"a", b"b", b"c");
+    data[0] = 6; // MasterEditionV2
+    assert_eq!(metadata_name(&data), Err(ProgramError::InvalidAccountData));
+    assert_eq!(metadata_mint(&data), Err(ProgramError::InvalidAccountData));
+    assert_eq!(metadata_name(&[]), Err(ProgramError::AccountDataTooSmall));
+}
+
+#[test]
+fn rejects_truncated_or_oversized_lengths() {
+    let data = metadata(b"a", b"b", b"c");
+    assert_eq!(metadata_uri(&data[..data.len() - 1]), Err(ProgramError::AccountDataTooSmall));
+    assert_eq!(metadata_name(&data[..67]), Err(ProgramError::AccountDataTooSmall));
+
+    let mut bad = data.clone();
+    bad[65..69].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert_eq!(metadata_name(&bad), Err(ProgramError::AccountDataTooSmall));
+    assert_eq!(metadata_symbol(&bad), Err(ProgramError::AccountDataTooSmall));
+}
+
+// ── Collection ───────────────────────────────────────────────────────────────
+
+#[test]
+fn verified_matching_collection_passes() {
+    let collection = Address::new_from_array(COLLECTION);
+    for creators in [0, 1, 3] {
+        let data = with_tail(metadata(b"a", b"b", b"c"), creators, Some((true, COLLECTION)));
+        assert_eq!(
+            metadata_collection(&data),
+            Ok(Some(MetadataCollection { verified: true, key: collection }))
+        );
+        assert!(check_verified_collection(&data, &collection).is_ok());
+    }
+}
+
+#[test]
+fn unverified_collection_fails() {
+    let data = with_tail(metadata(b"a", b"b", b"c"), 2, Some((false, COLLECTION)));
+    assert_eq!(
+        check_verified_collection(&data, &Address::new_from_array(COLLECTION)),
+        Err(ProgramError::InvalidArgument)
+    );
+}
+
+#[test]
+fn wrong_or_missing_collection_fails() {
+    let other = Address::new_from_array([0xC1; 32]);
+    let data = with_tail(metadata(b"a", b"b", b"c"), 1, Some((true, COLLECTION)));
+    assert_eq!(check_verified_collection(&data, &other), Err(ProgramError::InvalidArgument));
+
+    let none = with_tail(metadata(b"a", b"b", b"c"), 1, None);
+    assert_eq!(metadata_collection(&none), Ok(None));
+    assert_eq!(check_verified_collection(&none, &other), Err(ProgramError::InvalidArgument));
+
+    // Legacy account that ends right after the strings.
+    let legacy = metadata(b"a", b"b", b"c");
+    assert_eq!(metadata_collection(&legacy), Ok(None));
+}
+
+#[test]
+fn truncated_collection_is_rejected() {
+    let data = with_tail(metadata(b"a", b"b", b"c"), 1, Some((true, COLLECTION)));
+    let cut = data.len() - 16 - 10;
+    assert_eq!(metadata_collection(&data[..cut]), Err(ProgramError::AccountDataTooSmall));
+
+    // Cut inside the creators vec.
+    let mid_creators = metadata(b"a", b"b", b"c").len() + 2 + 5 + 20;
+    assert_eq!(metadata_collection(&data[..mid_creators]), Err(ProgramError::AccountDataTooSmall));
+}
@@ -0,0 +1,54 @@
+//! TokenAccount view over raw SPL Token account bytes.
+
+use core::cell::{Ref, RefCell};
+
+use jiminy_solana::token::{TokenAccount, TOKEN_ACCOUNT_LEN};
+use jiminy_solana::{Address, ProgramError};
+
+fn token_account_bytes() -> [u8; TOKEN_ACCOUNT_LEN] {
+    let mut d = [0u8; TOKEN_ACCOUNT_LEN];
+    d[0..32].fill(1);
+    d[32..64].fill(2);
+    d[64..72].copy_from_slice(&500u64.to_le_bytes());
+    d[72] = 1;
+    d[76..108].fill(3);
+    d[108] = 2;
+    d[121..129].copy_from_slice(&40u64.to_le_bytes());
+    d
+}
+
+#[test]
+fn reads_every_field() {
+    let cell = RefCell::new(token_account_bytes());
+    let view = TokenAccount::new(Ref::map(cell.borrow(), |d| &d[..])).unwrap();
+    assert_eq!(*view.mint(), Address::new_from_array([1; 32]));
+    assert_eq!(*view.owner(), Address::new_from_array([2; 32]));
+    assert_eq!(view.amount(), 500);
+    assert_eq!(view.delegate(), Some(&Address::new_from_array([3; 32])));
+    assert_eq!(view.state(), 2);
+    assert!(view.is_initialized());
+    assert!(view.is_frozen());
+    assert_eq!(view.native_amount(), None);
+    assert_eq!(view.delegated_amount(), 40);
+    assert_eq!(view.close_authority(), None);
+}
+
+#[test]
+fn reads_native_amount_and_close_authority() {
+    let mut d = token_account_bytes();
+    d[109] = 1;
+    d[113..121].copy_from_slice(&2_039_280u64.to_le_bytes());
+    d[129] = 1;
+    d[133..165].fill(4);
+    let cell = RefCell::new(d);
+    let view = TokenAccount::new(Ref::map(cell.borrow(), |d| &d[..])).unwrap();
+    assert_eq!(view.native_amount(), Some(2_039_280));
+    assert_eq!(view.close_authority(), Some(&Address::new_from_array([4; 32])));
+}
+
+#[test]
+fn rejects_short_data() {
+    let cell = RefCell::new([0u8; TOKEN_ACCOUNT_LEN - 1]);
+    let view = TokenAccount::new(Ref::map(cell.borrow(), |d| &d[..]));
+    assert!(matches!(view, Err(ProgramError::AccountDataTooSmall)));
+}
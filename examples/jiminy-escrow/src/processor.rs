@@ -11,14 +11,11 @@ pub fn process(
     accounts: &[AccountView],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    let mut ix = SliceCursor::new(instruction_data);
-    let tag = ix.read_u8()?;
-
-    match tag {
+    instruction_dispatch! {
+        program_id, accounts, instruction_data; ix;
         IX_CREATE_ESCROW => process_create_escrow(program_id, accounts, &ix),
         IX_ACCEPT_ESCROW => process_accept_escrow(program_id, accounts),
         IX_CANCEL_ESCROW => process_cancel_escrow(program_id, accounts),
-        _ => Err(ProgramError::InvalidInstructionData),
     }
 }
 
@@ -33,6 +30,7 @@ pub fn process(
 //   [0..8]   u64     amount
 //   [8..40]  Address recipient
 //   [40..48] i64     timeout_ts (0 = no timeout)
+//   [48..80] Address linked_account (all zeros = none)
 
 fn process_create_escrow(
     program_id: &Address,
@@ -50,6 +48,7 @@ fn process_create_escrow(
     let amount = args.read_u64()?;
     let recipient_addr = args.read_address()?;
     let timeout_ts = args.read_i64()?;
+    let linked_addr = args.read_address()?;
 
     require!(amount > 0, ProgramError::InvalidArgument);
 
@@ -74,6 +73,7 @@ fn process_create_escrow(
     e.creator = *creator.address();
     e.recipient = recipient_addr;
     e.timeout = timeout_ts;
+    e.linked = linked_addr;
 
     Ok(())
 }
@@ -99,10 +99,10 @@ fn process_accept_escrow(
     {
         let data = escrow.try_borrow()?;
         check_header(&data, ESCROW_DISC, ESCROW_VERSION, &ESCROW_LAYOUT_ID)?;
-        let flags = read_header_flags(&data)? as u8;
+        let flags = EscrowFlags::from_bits(read_flags_at(&data, FLAGS_OFFSET)?);
 
         // Must not already be accepted.
-        require!(!read_bit(flags, FLAG_ACCEPTED), ProgramError::InvalidAccountData);
+        require!(!flags.contains(EscrowFlags::ACCEPTED), ProgramError::InvalidAccountData);
 
         let e = Escrow::overlay(&data)?;
         amount = e.amount;
@@ -120,9 +120,9 @@ fn process_accept_escrow(
     // Mark accepted flag.
     {
         let mut raw = escrow.try_borrow_mut()?;
-        let flags = read_header_flags(&raw)? as u8;
-        let new_flags = set_bit(flags, FLAG_ACCEPTED);
-        raw[2] = new_flags;
+        let mut flags = EscrowFlags::from_bits(read_flags_at(&raw, FLAGS_OFFSET)?);
+        flags.insert(EscrowFlags::ACCEPTED);
+        write_flags_at(&mut raw, FLAGS_OFFSET, flags.bits())?;
     }
 
     Ok(())
@@ -134,11 +134,15 @@ fn process_accept_escrow(
 //   0. [signer]          creator
 //   1. [writable]        escrow
 //   2. [writable]        destination (receives remaining lamports)
-//   3. []                linked_account (optional; if provided, must be closed)
+//   3. []                linked_account (optional; must be the one stored at
+//                        create time, and closed)
 //
 // The creator can cancel if:
 //   - The escrow has not been accepted, AND
-//   - Either a timeout has passed, or the linked account (if provided) is closed.
+//   - Either the escrow stores a linked account and that account is
+//     provided and closed, or `timeout_ts` (non-zero) has passed on the
+//     Clock sysvar. Extra accounts on an escrow with no linked account
+//     are ignored, so they cannot skip the timeout.
 
 fn process_cancel_escrow(
     program_id: &Address,
@@ -151,24 +155,33 @@ fn process_cancel_escrow(
 
     require_accounts_ne!(escrow, destination, ProgramError::InvalidArgument);
 
+    let timeout;
+    let linked_addr;
     {
         let data = escrow.try_borrow()?;
         check_header(&data, ESCROW_DISC, ESCROW_VERSION, &ESCROW_LAYOUT_ID)?;
-        let flags = read_header_flags(&data)? as u8;
+        let flags = EscrowFlags::from_bits(read_flags_at(&data, FLAGS_OFFSET)?);
 
         // Must not already be accepted.
-        require!(!read_bit(flags, FLAG_ACCEPTED), ProgramError::InvalidAccountData);
+        require!(!flags.contains(EscrowFlags::ACCEPTED), ProgramError::InvalidAccountData);
 
         let e = Escrow::overlay(&data)?;
+        timeout = e.timeout;
+        linked_addr = e.linked;
 
         // Creator must match.
         check_has_one(&e.creator, creator)?;
     }
 
-    // If a linked account is provided, verify it's been closed.
-    if accs.remaining() > 0 {
+    if *linked_addr.as_array() != [0u8; 32] && accs.remaining() > 0 {
+        // The stored linked account is provided: it must already be closed.
         let linked = accs.next()?;
+        check_has_one(&linked_addr, linked)?;
         check_closed(linked)?;
+    } else {
+        // Otherwise the timeout must be set and have passed.
+        require!(timeout != 0, ProgramError::InvalidArgument);
+        check_expired(clock_timestamp()?, timeout)?;
     }
 
     safe_close(escrow, destination)?;
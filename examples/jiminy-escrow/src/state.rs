@@ -8,6 +8,7 @@ zero_copy_layout! {
         creator:   Address       = 32,
         recipient: Address       = 32,
         timeout:   i64           = 8,
+        linked:    Address       = 32,
     }
 }
 
@@ -17,7 +18,7 @@ pub const ESCROW_DISC: u8 = Escrow::DISC;
 /// Escrow account version.
 pub const ESCROW_VERSION: u8 = Escrow::VERSION;
 
-/// Total size of an escrow account (16 header + 8 + 32 + 32 + 8 = 96).
+/// Total size of an escrow account (16 header + 8 + 32 + 32 + 8 + 32 = 128).
 pub const ESCROW_LEN: usize = Escrow::LEN;
 
 /// Escrow layout ID.
@@ -28,7 +29,15 @@ pub const AMOUNT_OFFSET: usize = 0;
 pub const CREATOR_OFFSET: usize = 8;
 pub const RECIPIENT_OFFSET: usize = 40;
 pub const TIMEOUT_OFFSET: usize = 72;
+pub const LINKED_OFFSET: usize = 80;
 
-// Flag bits (byte 2 of header).
-/// Set when the escrow has been accepted by the recipient.
-pub const FLAG_ACCEPTED: u8 = 0;
+/// Header flags byte offset (low byte of the header `flags` field).
+pub const FLAGS_OFFSET: usize = 2;
+
+flags! {
+    /// Escrow lifecycle flags (byte 2 of header).
+    pub struct EscrowFlags: u8 {
+        /// Set when the escrow has been accepted by the recipient.
+        ACCEPTED = 0,
+    }
+}
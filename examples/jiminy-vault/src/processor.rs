@@ -14,15 +14,12 @@ pub fn process(
     accounts: &[AccountView],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    let mut ix = SliceCursor::new(instruction_data);
-    let tag = ix.read_u8()?;
-
-    match tag {
+    instruction_dispatch! {
+        program_id, accounts, instruction_data; ix;
         IX_INIT_VAULT => process_init_vault(program_id, accounts, &ix),
         IX_DEPOSIT => process_deposit(program_id, accounts, &ix),
         IX_WITHDRAW => process_withdraw(program_id, accounts, &ix),
         IX_CLOSE_VAULT => process_close_vault(program_id, accounts),
-        _ => Err(ProgramError::InvalidInstructionData),
     }
 }
 
@@ -42,11 +39,7 @@ fn process_init_vault(
     ix: &SliceCursor,
 ) -> ProgramResult {
     let mut accs = AccountList::new(accounts);
-    let payer = accs.next_writable_signer()?;
-    let vault = accs.next_writable()?;
-    let _system = accs.next_system_program()?;
-
-    check_uninitialized(vault)?;
+    let (payer, vault, _system) = accs.next_init_triple()?;
 
     // Read authority from instruction data - cursor is past the tag byte.
     let mut args = SliceCursor::new(ix.data_from_position());
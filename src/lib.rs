@@ -40,6 +40,7 @@
 //! | [`account`] | Header, reader, writer, cursor, lifecycle, pod, overlay, collection, list, bits |
 //! | [`abi`] | Alignment-safe LE wire types (`LeU64`, `FieldRef`, `FieldMut`) |
 //! | [`check`] | Validation checks, asserts, PDA derivation & verification |
+//! | [`error`] | `JiminyError` codes for the checks *(feature: `granular-errors`)* |
 //! | [`math`] | Checked arithmetic, BPS, scaling |
 //! | [`instruction`] | Transaction introspection (sysvar Instructions) |
 //! | [`interface`] | Read-only foreign account interface macro |
@@ -61,8 +62,12 @@
 //! | [`compute`] | Compute budget guards |
 //! | [`compose`] | Transaction composition guards (flash-loan detection) |
 //! | [`introspect`] | Raw transaction introspection |
+//! | [`metadata`] | Metaplex Token Metadata readers |
 //! | [`oracle`] | Pyth V2 price feed readers |
+//! | [`sys`] | System program CPI builders |
+//! | [`token_cpi`] | SPL Token / Token-2022 CPI builders |
 //! | [`twap`] | TWAP accumulators |
+//! | [`ata_cpi`] | Associated Token Account CPI builders *(feature: `programs`)* |
 //! | [`upgrade`] | Program upgrade authority verification *(feature: `programs`)* |
 //!
 //! ## Community / Domain Extensions (Not Core)
@@ -104,12 +109,23 @@
 //! | Macro | |
 //! |---|---|
 //! | [`require!`] | `if !cond { return Err(e) }` -- the universal guard |
+//! | [`require_msg!`] | [`require!`] that logs a message on failure *(feature: `log`)* |
+//! | [`require_some!`] | Unwrap an `Option` or return the error |
 //! | [`require_keys_eq!`] | Two owned or borrowed `Address` operands must match |
 //! | [`require_keys_neq!`] | Two owned or borrowed `Address` operands must differ |
+//! | [`require_account_key_eq!`] | An account's address must equal an `Address` |
+//! | [`require_account_key_neq!`] | An account's address must differ from an `Address` |
+//! | [`require_keys_in!`] | `Address` must be one of an allowlist |
+//! | [`require_owner!`] | Account owner check with a custom error |
+//! | [`require_signer!`] | Account must be a signer, with a custom error |
+//! | [`require_writable!`] | Account must be writable, with a custom error |
+//! | [`require_rent_exempt!`] | Account must be rent-exempt, with a custom error |
+//! | [`require_lamports_gte!`] | Account lamports `>=` amount, with a custom error |
 //! | [`require_gte!`] | `a >= b` |
 //! | [`require_gt!`] | `a > b` |
 //! | [`require_lt!`] | `a < b` |
 //! | [`require_lte!`] | `a <= b` |
+//! | [`checked_require_sub!`] | `a >= b`, evaluating to `a - b` |
 //! | [`require_eq!`] | Scalar equality |
 //! | [`require_neq!`] | Scalar inequality |
 //! | [`require_flag!`] | Bit must be set |
@@ -121,16 +137,22 @@
 //! |---|---|
 //! | [`error_codes!`] | Sequential error constants + `Into<ProgramError>` |
 //! | [`instruction_dispatch!`] | Byte-tag dispatch to handler functions |
+//! | [`dispatch!`] | Byte-tag dispatch to same-signature handlers |
+//! | [`disc_of!`] | Compile-time 1-byte discriminator from a type name |
+//! | [`unique_discriminators!`] | Compile-time check that discriminators don't collide |
+//! | [`static_assert!`] | Compile-time check on layout constants |
+//! | [`flags!`] | Typed bit-flag set with mask constants over a backing integer |
 //!
 //! ### PDA
 //!
 //! | Macro | |
 //! |---|---|
-//! | `find_pda!` | Find canonical PDA + bump via syscall |
+//! | `find_pda!` | Find canonical PDA + bump (syscall on-chain, pure Rust off-chain) |
 //! | `derive_pda!` | Derive PDA with known bump (~100 CU) |
 //! | `derive_pda_const!` | Compile-time PDA derivation |
 //! | `derive_ata_const!` | Compile-time ATA derivation |
 //! | `require_pda!` | Derive + assert PDA match, return bump |
+//! | `signer_seeds!` | Bind seeds + bump as a `Signer` for `invoke_signed` |
 //!
 //! ### Events
 //!
@@ -140,7 +162,9 @@
 
 // ── Ring 1: systems layer (from jiminy-core) ─────────────────────────────────
 
-pub use jiminy_core::{abi, account, check, compat, event, instruction, interface, math, state, sysvar, time};
+pub use jiminy_core::{
+    abi, account, check, compat, error, event, instruction, interface, math, state, sysvar, time,
+};
 
 #[cfg(feature = "programs")]
 pub use jiminy_core::programs;
@@ -151,11 +175,12 @@ pub use jiminy_core::log;
 // ── Ring 2: platform helpers (from jiminy-solana) ────────────────────────────
 
 pub use jiminy_solana::{
-    authority, balance, compute, compose, cpi, crypto, introspect, oracle, token, twap,
+    authority, balance, compute, compose, cpi, crypto, introspect, metadata, oracle, sys,
+    token, token_cpi, twap,
 };
 
 #[cfg(feature = "programs")]
-pub use jiminy_solana::upgrade;
+pub use jiminy_solana::{ata_cpi, upgrade};
 
 // ── Ring 3+: protocol domain crates ──────────────────────────────────────────
 
@@ -223,15 +248,57 @@ pub mod prelude;
 // correctly in each context. Keep both copies in sync.
 
 /// Require a boolean condition: return `$err` (converted via `Into`) if false.
+///
+/// With the `log-on-failure` feature, this and every other `require_*`
+/// macro log the `file:line` of the failing check before returning.
 #[macro_export]
 macro_rules! require {
     ($cond:expr, $err:expr $(,)?) => {
         if !($cond) {
+            $crate::jiminy_core::__require_failed(concat!(file!(), ":", line!()));
+            return Err($err.into());
+        }
+    };
+}
+
+/// [`require!`] that logs `$msg` before returning `$err`.
+///
+/// The message goes to the program log when the `log` feature is enabled
+/// and compiles away otherwise, so the happy path costs nothing either way.
+///
+/// ```rust,ignore
+/// require_msg!(amount > 0, MyError::ZeroAmount, "deposit: amount is zero");
+/// ```
+#[macro_export]
+macro_rules! require_msg {
+    ($cond:expr, $err:expr, $msg:expr $(,)?) => {
+        if !($cond) {
+            $crate::jiminy_core::__log_failure($msg);
             return Err($err.into());
         }
     };
 }
 
+/// Unwrap an `Option`: evaluate to the inner value, or return `$err` if `None`.
+///
+/// The `Option` counterpart to [`require!`].
+///
+/// ```rust,ignore
+/// let delegate = require_some!(token_account_delegate(acc)?, MyError::NoDelegate);
+/// ```
+#[macro_export]
+macro_rules! require_some {
+    ($opt:expr, $err:expr $(,)?) => {
+        match $opt {
+            Some(__jiminy_value) => __jiminy_value,
+            None => {
+                $crate::jiminy_core::__require_failed(concat!(file!(), ":", line!()));
+                return Err($err.into());
+            }
+        }
+    };
+}
+
 /// Require two [`Address`] values to be equal.
 ///
 /// Accepts owned `Address` values, `&Address` references, or a mix of both.
@@ -241,6 +308,7 @@ macro_rules! require_keys_eq {
         let __jiminy_a: &$crate::Address = &$a;
         let __jiminy_b: &$crate::Address = &$b;
         if __jiminy_a != __jiminy_b {
+            $crate::jiminy_core::__require_failed(concat!(file!(), ":", line!()));
             return Err($err.into());
         }
     };
@@ -255,6 +323,75 @@ macro_rules! require_keys_neq {
         let __jiminy_a: &$crate::Address = &$a;
         let __jiminy_b: &$crate::Address = &$b;
         if __jiminy_a == __jiminy_b {
+            $crate::jiminy_core::__require_failed(concat!(file!(), ":", line!()));
+            return Err($err.into());
+        }
+    };
+}
+
+/// Require an account's address to equal `$expected`.
+///
+/// Account-side counterpart of [`require_keys_eq!`]: calls `.address()`
+/// on `$account`, so `$expected` is the only operand to spell out.
+///
+/// ```rust,ignore
+/// require_account_key_eq!(vault, &state.vault, MyError::WrongVault);
+/// ```
+#[macro_export]
+macro_rules! require_account_key_eq {
+    ($account:expr, $expected:expr, $err:expr $(,)?) => {
+        let __jiminy_expected: &$crate::Address = &$expected;
+        if $account.address() != __jiminy_expected {
+            $crate::jiminy_core::__require_failed(concat!(file!(), ":", line!()));
+            return Err($err.into());
+        }
+    };
+}
+
+/// Require an account's address to differ from `$other`.
+///
+/// Account-side counterpart of [`require_keys_neq!`].
+#[macro_export]
+macro_rules! require_account_key_neq {
+    ($account:expr, $other:expr, $err:expr $(,)?) => {
+        let __jiminy_other: &$crate::Address = &$other;
+        if $account.address() == __jiminy_other {
+            $crate::jiminy_core::__require_failed(concat!(file!(), ":", line!()));
+            return Err($err.into());
+        }
+    };
+}
+
+/// Require `$account` to be owned by `$program_id`, else return `$err`.
+///
+/// Like [`check_owner`](crate::check::check_owner), but with a caller-chosen
+/// error instead of `IncorrectProgramId`. `$program_id` may be an owned
+/// `Address` or a reference.
+#[macro_export]
+macro_rules! require_owner {
+    ($account:expr, $program_id:expr, $err:expr $(,)?) => {
+        let __jiminy_owner: &$crate::Address = &$program_id;
+        if !$account.owned_by(__jiminy_owner) {
+            $crate::jiminy_core::__require_failed(concat!(file!(), ":", line!()));
+            return Err($err.into());
+        }
+    };
+}
+
+/// Require `$key` to equal one of the addresses in `$keys`.
+///
+/// For allowlists of authorities or accepted mints:
+///
+/// ```rust,ignore
+/// require_keys_in!(mint.address(), &[USDC_MINT, USDT_MINT], MyError::MintNotAccepted);
+/// ```
+#[macro_export]
+macro_rules! require_keys_in {
+    ($key:expr, $keys:expr, $err:expr $(,)?) => {
+        let __jiminy_key: &$crate::Address = &$key;
+        let __jiminy_keys: &[$crate::Address] = $keys;
+        if !__jiminy_keys.contains(__jiminy_key) {
+            $crate::jiminy_core::__require_failed(concat!(file!(), ":", line!()));
             return Err($err.into());
         }
     };
@@ -265,6 +402,65 @@ macro_rules! require_keys_neq {
 macro_rules! require_accounts_ne {
     ($a:expr, $b:expr, $err:expr $(,)?) => {
         if $a.address() == $b.address() {
+            $crate::jiminy_core::__require_failed(concat!(file!(), ":", line!()));
+            return Err($err.into());
+        }
+    };
+}
+
+/// Require `$account` to have signed the transaction, else return `$err`.
+///
+/// Like [`check_signer`](crate::check::check_signer), but with a caller-chosen
+/// error instead of `MissingRequiredSignature`.
+#[macro_export]
+macro_rules! require_signer {
+    ($account:expr, $err:expr $(,)?) => {
+        if !$account.is_signer() {
+            $crate::jiminy_core::__require_failed(concat!(file!(), ":", line!()));
+            return Err($err.into());
+        }
+    };
+}
+
+/// Require `$account` to be writable, else return `$err`.
+///
+/// Like [`check_writable`](crate::check::check_writable), but with a
+/// caller-chosen error instead of `InvalidArgument`.
+#[macro_export]
+macro_rules! require_writable {
+    ($account:expr, $err:expr $(,)?) => {
+        if !$account.is_writable() {
+            $crate::jiminy_core::__require_failed(concat!(file!(), ":", line!()));
+            return Err($err.into());
+        }
+    };
+}
+
+/// Require `$account` to hold enough lamports to be rent-exempt for its
+/// current data length, else return `$err`.
+///
+/// Like [`check_rent_exempt`](crate::check::check_rent_exempt), but with a
+/// caller-chosen error instead of `InsufficientFunds`.
+#[macro_export]
+macro_rules! require_rent_exempt {
+    ($account:expr, $err:expr $(,)?) => {{
+        let account = &$account;
+        if account.lamports() < $crate::check::rent_exempt_min(account.data_len()) {
+            $crate::jiminy_core::__require_failed(concat!(file!(), ":", line!()));
+            return Err($err.into());
+        }
+    }};
+}
+
+/// Require `$account` to hold at least `$lamports`, else return `$err`.
+///
+/// Like [`check_lamports_gte`](crate::check::check_lamports_gte), but with
+/// a caller-chosen error.
+#[macro_export]
+macro_rules! require_lamports_gte {
+    ($account:expr, $lamports:expr, $err:expr $(,)?) => {
+        if $account.lamports() < $lamports {
+            $crate::jiminy_core::__require_failed(concat!(file!(), ":", line!()));
             return Err($err.into());
         }
     };
@@ -275,6 +471,7 @@ macro_rules! require_accounts_ne {
 macro_rules! require_gte {
     ($a:expr, $b:expr, $err:expr $(,)?) => {
         if $a < $b {
+            $crate::jiminy_core::__require_failed(concat!(file!(), ":", line!()));
             return Err($err.into());
         }
     };
@@ -285,6 +482,7 @@ macro_rules! require_gte {
 macro_rules! require_gt {
     ($a:expr, $b:expr, $err:expr $(,)?) => {
         if $a <= $b {
+            $crate::jiminy_core::__require_failed(concat!(file!(), ":", line!()));
             return Err($err.into());
         }
     };
@@ -295,6 +493,7 @@ macro_rules! require_gt {
 macro_rules! require_lt {
     ($a:expr, $b:expr, $err:expr $(,)?) => {
         if $a >= $b {
+            $crate::jiminy_core::__require_failed(concat!(file!(), ":", line!()));
             return Err($err.into());
         }
     };
@@ -305,16 +504,39 @@ macro_rules! require_lt {
 macro_rules! require_lte {
     ($a:expr, $b:expr, $err:expr $(,)?) => {
         if $a > $b {
+            $crate::jiminy_core::__require_failed(concat!(file!(), ":", line!()));
             return Err($err.into());
         }
     };
 }
 
+/// Require `a >= b` and evaluate to `a - b`, else return `$err`.
+///
+/// The "ensure enough, then subtract" step as one expression. Each
+/// operand is evaluated once.
+///
+/// ```rust,ignore
+/// let remaining = checked_require_sub!(balance, amount, ProgramError::InsufficientFunds);
+/// ```
+#[macro_export]
+macro_rules! checked_require_sub {
+    ($a:expr, $b:expr, $err:expr $(,)?) => {{
+        let a = $a;
+        let b = $b;
+        if a < b {
+            $crate::jiminy_core::__require_failed(concat!(file!(), ":", line!()));
+            return Err($err.into());
+        }
+        a - b
+    }};
+}
+
 /// Require `a == b` for scalar types.
 #[macro_export]
 macro_rules! require_eq {
     ($a:expr, $b:expr, $err:expr $(,)?) => {
         if $a != $b {
+            $crate::jiminy_core::__require_failed(concat!(file!(), ":", line!()));
             return Err($err.into());
         }
     };
@@ -325,6 +547,7 @@ macro_rules! require_eq {
 macro_rules! require_neq {
     ($a:expr, $b:expr, $err:expr $(,)?) => {
         if $a == $b {
+            $crate::jiminy_core::__require_failed(concat!(file!(), ":", line!()));
             return Err($err.into());
         }
     };
@@ -335,11 +558,119 @@ macro_rules! require_neq {
 macro_rules! require_flag {
     ($byte:expr, $n:expr, $err:expr $(,)?) => {
         if ($byte >> $n) & 1 == 0 {
+            $crate::jiminy_core::__require_failed(concat!(file!(), ":", line!()));
             return Err($err.into());
         }
     };
 }
 
+/// Declare a typed set of bit flags over a backing integer.
+///
+/// Each flag is given by its bit *index*; the macro turns it into a
+/// typed mask constant, so an index can never be passed where a mask is
+/// expected (or vice versa). The wrapper is `#[repr(transparent)]` and
+/// every method is `const` or `#[inline(always)]`.
+///
+/// ```rust,ignore
+/// flags! {
+///     /// Escrow lifecycle flags.
+///     pub struct EscrowFlags: u8 {
+///         ACCEPTED = 0,
+///         CANCELLED = 1,
+///     }
+/// }
+///
+/// let mut flags = EscrowFlags::from_bits(read_flags_at(&data, 2)?);
+/// require!(!flags.contains(EscrowFlags::ACCEPTED), ProgramError::InvalidAccountData);
+/// flags.insert(EscrowFlags::ACCEPTED);
+/// write_flags_at(&mut data, 2, flags.bits())?;
+/// ```
+#[macro_export]
+macro_rules! flags {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident : $ty:ty {
+            $( $(#[$fmeta:meta])* $flag:ident = $bit:expr ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+        #[repr(transparent)]
+        $vis struct $name($ty);
+
+        #[allow(dead_code)]
+        impl $name {
+            $(
+                $(#[$fmeta])*
+                pub const $flag: Self = Self(1 << $bit);
+            )*
+
+            /// Every declared flag.
+            pub const ALL: Self = Self(0 $( | (1 << $bit) )*);
+
+            /// No flags set.
+            #[inline(always)]
+            pub const fn empty() -> Self {
+                Self(0)
+            }
+
+            /// Wrap a raw value read from account data.
+            #[inline(always)]
+            pub const fn from_bits(bits: $ty) -> Self {
+                Self(bits)
+            }
+
+            /// The raw value, for writing back to account data.
+            #[inline(always)]
+            pub const fn bits(self) -> $ty {
+                self.0
+            }
+
+            /// `true` if every flag in `other` is set.
+            #[inline(always)]
+            pub const fn contains(self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// `true` if any flag in `other` is set.
+            #[inline(always)]
+            pub const fn intersects(self, other: Self) -> bool {
+                self.0 & other.0 != 0
+            }
+
+            /// `true` if no bits outside the declared flags are set.
+            #[inline(always)]
+            pub const fn is_known(self) -> bool {
+                self.0 & !Self::ALL.0 == 0
+            }
+
+            /// Combine two flag sets.
+            #[inline(always)]
+            pub const fn union(self, other: Self) -> Self {
+                Self(self.0 | other.0)
+            }
+
+            /// Set the flags in `other`.
+            #[inline(always)]
+            pub fn insert(&mut self, other: Self) {
+                self.0 |= other.0;
+            }
+
+            /// Clear the flags in `other`.
+            #[inline(always)]
+            pub fn remove(&mut self, other: Self) {
+                self.0 &= !other.0;
+            }
+
+            /// Flip the flags in `other`.
+            #[inline(always)]
+            pub fn toggle(&mut self, other: Self) {
+                self.0 ^= other.0;
+            }
+        }
+    };
+}
+
 /// Verify that all passed accounts have unique addresses.
 ///
 /// Variadic - works with 2, 3, 4, or more accounts. Expands to
@@ -416,6 +747,65 @@ macro_rules! error_codes {
     };
 }
 
+/// Derive a 1-byte account discriminator from a type name at compile time.
+///
+/// Takes the first non-zero byte of `sha256(name)`, so the result is
+/// stable across builds and never collides with zero-filled data.
+///
+/// One byte only gives 255 values. With `n` account types the chance of
+/// at least one clash is about `1 - e^(-n(n-1)/510)`: ~4% for 5 types,
+/// ~16% for 10, ~52% for 20. Pair with [`unique_discriminators!`] so a
+/// clash fails the build instead of aliasing two layouts.
+///
+/// ```rust,ignore
+/// const VAULT_DISC: u8 = disc_of!("Vault");
+/// const POOL_DISC: u8 = disc_of!("Pool");
+/// unique_discriminators!(VAULT_DISC, POOL_DISC);
+/// ```
+#[macro_export]
+macro_rules! disc_of {
+    ($name:expr $(,)?) => {
+        $crate::jiminy_core::__disc_of($name)
+    };
+}
+
+/// Compile-time check that every listed discriminator is distinct.
+///
+/// Expands to a `const` assertion, so a duplicate is a build error.
+///
+/// ```rust,ignore
+/// unique_discriminators!(Vault::DISC, Pool::DISC, Position::DISC);
+/// ```
+#[macro_export]
+macro_rules! unique_discriminators {
+    ($($disc:expr),+ $(,)?) => {
+        const _: () = assert!(
+            $crate::jiminy_core::__all_unique(&[$($disc),+]),
+            "duplicate account discriminator"
+        );
+    };
+}
+
+/// Compile-time assertion for layout constants.
+///
+/// Expands to a `const` assertion, so a false condition is a build error
+/// rather than a mis-sized account found at runtime. Usable at item level
+/// or inside a function.
+///
+/// ```rust,ignore
+/// static_assert!(VAULT_LEN == HEADER_LEN + 8 + 32);
+/// static_assert!(BUMP_OFFSET + 1 <= VAULT_LEN, "bump outside the account");
+/// ```
+#[macro_export]
+macro_rules! static_assert {
+    ($cond:expr $(,)?) => {
+        const _: () = assert!($cond, "{}", concat!("static assertion failed: ", stringify!($cond)));
+    };
+    ($cond:expr, $msg:literal $(,)?) => {
+        const _: () = assert!($cond, $msg);
+    };
+}
+
 /// Route instruction data to handler functions based on a single-byte tag.
 ///
 /// Replaces Anchor's `#[program]` proc macro. Reads byte 0 as the
@@ -425,15 +815,35 @@ macro_rules! error_codes {
 /// ```rust,ignore
 /// instruction_dispatch! {
 ///     program_id, accounts, instruction_data;
-///     0 => process_init(program_id, accounts, ix),
-///     1 => process_deposit(program_id, accounts, ix),
-///     2 => process_withdraw(program_id, accounts, ix),
+///     0 => process_init(program_id, accounts),
+///     1 => process_close(program_id, accounts),
 /// }
 /// ```
 ///
-/// Inside each arm, `ix` is a `SliceCursor` positioned after the tag byte.
+/// To read arguments, name the cursor after the data. It is positioned
+/// after the tag, and tags may be any pattern (`const` tags, ranges):
+///
+/// ```rust,ignore
+/// instruction_dispatch! {
+///     program_id, accounts, instruction_data; ix;
+///     IX_INIT => process_init(program_id, accounts, &ix),
+///     IX_CLOSE => process_close(program_id, accounts),
+/// }
+/// ```
+///
+/// When every handler has the same signature, [`dispatch!`] is shorter.
 #[macro_export]
 macro_rules! instruction_dispatch {
+    (
+        $pid:expr, $accs:expr, $data:expr; $ix:ident;
+        $( $tag:pat => $handler:expr ),+ $(,)?
+    ) => {{
+        let mut $ix = $crate::account::SliceCursor::new($data);
+        match $ix.read_u8()? {
+            $( $tag => { let _ = &$ix; $handler } )+
+            _ => Err($crate::ProgramError::InvalidInstructionData),
+        }
+    }};
     (
         $pid:expr, $accs:expr, $data:expr;
         $( $tag:expr => $handler:expr ),+ $(,)?
@@ -447,6 +857,34 @@ macro_rules! instruction_dispatch {
     }};
 }
 
+/// Route instruction data to handlers that all take
+/// `(program_id, accounts, &SliceCursor)`.
+///
+/// [`instruction_dispatch!`] with the calls written for you: each handler
+/// gets the cursor positioned after the tag byte. Unknown tags return
+/// `InvalidInstructionData`. Tags are patterns.
+///
+/// ```rust,ignore
+/// dispatch! {
+///     program_id, accounts, instruction_data;
+///     IX_INIT => process_init,
+///     IX_DEPOSIT => process_deposit,
+/// }
+/// ```
+#[macro_export]
+macro_rules! dispatch {
+    (
+        $pid:expr, $accs:expr, $data:expr;
+        $( $tag:pat => $handler:path ),+ $(,)?
+    ) => {{
+        let mut ix = $crate::account::SliceCursor::new($data);
+        match ix.read_u8()? {
+            $( $tag => $handler($pid, $accs, &ix), )+
+            _ => Err($crate::ProgramError::InvalidInstructionData),
+        }
+    }};
+}
+
 /// Batch `unsafe impl Pod` for a list of types.
 ///
 /// ```rust,ignore
@@ -666,20 +1104,14 @@ macro_rules! check_account_strict {
 
 /// Find a PDA and return `(Address, u8)` with the canonical bump.
 ///
-/// Uses the `find_program_address` syscall. Only available on-chain.
+/// Uses the `find_program_address` syscall on-chain and the pure-Rust
+/// [`find_program_address`](check::pda::find_program_address) search
+/// off-chain, so host tests can call it.
 #[macro_export]
 macro_rules! find_pda {
     ($program_id:expr, $($seed:expr),+ $(,)?) => {{
-        #[cfg(target_os = "solana")]
-        {
-            let seeds: &[&[u8]] = &[$($seed.as_ref()),+];
-            ::hopper_runtime::Address::find_program_address(seeds, $program_id)
-        }
-        #[cfg(not(target_os = "solana"))]
-        {
-            let _ = ($program_id, $($seed),+);
-            unreachable!("find_pda! is only available on target solana")
-        }
+        let seeds: &[&[u8]] = &[$($seed.as_ref()),+];
+        $crate::check::pda::find_program_address(seeds, $program_id)
     }};
 }
 
@@ -725,3 +1157,38 @@ macro_rules! require_pda {
         $crate::check::assert_pda($account, seeds, $program_id)
     }};
 }
+
+/// Bind a PDA [`Signer`](hopper_runtime::cpi::Signer) for `invoke_signed`.
+///
+/// Appends the bump as the last seed and keeps the bump byte and the
+/// seed array alive in the caller's scope, which is the part that is
+/// easy to get wrong by hand. Seeds take anything that is `AsRef<[u8]>`,
+/// same as [`find_pda!`].
+///
+/// ```rust,ignore
+/// signer_seeds!(vault_signer = [b"vault", authority.address()], vault_bump);
+///
+/// TransferChecked {
+///     from: vault_tokens,
+///     mint,
+///     to: user_tokens,
+///     authority: vault,
+///     amount,
+///     decimals,
+/// }
+/// .invoke_signed(&[vault_signer])?;
+/// ```
+#[macro_export]
+macro_rules! signer_seeds {
+    ($signer:ident = [$($seed:expr),+ $(,)?], $bump:expr $(,)?) => {
+        let bump: [u8; 1] = [$bump];
+        let seeds = [
+            $( $crate::hopper_runtime::cpi::Seed::from({
+                let seed: &[u8] = $seed.as_ref();
+                seed
+            }), )+
+            $crate::hopper_runtime::cpi::Seed::from(&bump[..]),
+        ];
+        let $signer = $crate::hopper_runtime::cpi::Signer::from(&seeds[..]);
+    };
+}